@@ -8,4 +8,22 @@ pub mod opensrs;
 
 // Re-export common types for convenience
 pub use config::{ConfigError, OpenSrsCredentials};
-pub use opensrs::{ClientConfig, Environment, ExpiringDomain, OpenSrsClient, OpenSrsError};
+#[cfg(feature = "async")]
+pub use opensrs::AsyncOpenSrsClient;
+/// Alias for [`OpenSrsClient`] under the `blocking` feature name used by
+/// crates that default to async (`reqwest`, `tonic`, ...) for their
+/// synchronous variant.
+///
+/// [`OpenSrsClient`] has no async dependencies and is always available, so
+/// this alias gates nothing on its own — it exists so callers who go looking
+/// for a `blocking` feature find one instead of having to discover that the
+/// sync client here is already unconditional.
+#[cfg(feature = "blocking")]
+pub use opensrs::OpenSrsClient as BlockingOpenSrsClient;
+pub use opensrs::{
+    CacheConfig, ClientConfig, ContactInfo, ContactInfoBuilder, ContactSet, ContactSetBuilder,
+    DnsRecord, DomainAvailability, Environment, ExpiringDomain, OpenSrsClient, OpenSrsError,
+    OpsValue, OrderReceipt, ParsedResponse, RegistrationOptions, RegistrationOptionsBuilder,
+    RenewalConfig, RenewalOutcome, RenewalSchedulerHandle, RetryOn, RetryPolicy, ThrottleConfig,
+    TransferStatus,
+};
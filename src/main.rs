@@ -1,6 +1,6 @@
 use chrono::NaiveDate;
 use macaw::config::OpenSrsCredentials;
-use macaw::{ClientConfig, Environment, OpenSrsClient};
+use macaw::{ClientConfig, Environment, OpenSrsClient, RetryPolicy, ThrottleConfig};
 use std::env;
 
 fn main() {
@@ -29,9 +29,18 @@ fn main() {
                 username: creds.username,
                 credential: creds.credential,
                 environment,
+                cache: None,
+                throttle: ThrottleConfig::default(),
+                retry: RetryPolicy::default(),
             };
 
-            let client = OpenSrsClient::new(config);
+            let client = match OpenSrsClient::new(config) {
+                Ok(client) => client,
+                Err(e) => {
+                    eprintln!("✗ Error initializing OpenSRS client: {}", e);
+                    std::process::exit(1);
+                }
+            };
 
             // Test domain listing for 2026
             println!();
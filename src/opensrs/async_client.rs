@@ -0,0 +1,194 @@
+//! Async OpenSRS client
+//!
+//! Mirrors [`OpenSrsClient`](super::client::OpenSrsClient) but issues
+//! requests over a non-blocking HTTP backend, so callers don't block a
+//! thread per request and can drive it from a tokio-based service.
+//!
+//! Pagination ([`send_paginated`](AsyncOpenSrsClient::send_paginated)) still
+//! fetches pages one at a time, in order, rather than fanning out
+//! concurrently: each page's `remainder` is only known after the previous
+//! page lands, so a generic fan-out would mean guessing a page count before
+//! page 0 responds. Not blocking a thread per page is the win here, not
+//! concurrent fetches.
+//!
+//! Signing ([`auth`](super::auth)), the XML codec ([`xml`](super::xml)),
+//! and the [`RetryPolicy`](super::retry::RetryPolicy) backoff schedule stay
+//! shared with the sync client; only the transport and the concurrency/rate
+//! governor differ. Waiting for a throttle slot or a retry backoff both use
+//! [`tokio::time::sleep`] rather than the sync client's blocking
+//! `Mutex`/`Condvar` wait and `std::thread::sleep`, so a send yields its
+//! task back to the runtime instead of blocking a worker thread for the
+//! duration of the wait. See [`AsyncThrottle`](super::throttle::AsyncThrottle).
+
+use super::auth::generate_signature;
+use super::command::{OpsCommand, OpsResponse, Paginated};
+use super::error::{classify_response_error, OpenSrsError, Result};
+use super::retry::backoff_delay;
+use super::throttle::AsyncThrottle;
+use super::types::{
+    ClientConfig, ExpiringDomain, GetDomainsByExpireDateAttrs, GetDomainsByExpireDateRequest,
+};
+use super::xml::{deserialize_response, serialize_request};
+use chrono::NaiveDate;
+
+/// Async variant of [`OpenSrsClient`](super::client::OpenSrsClient), built on `reqwest`
+pub struct AsyncOpenSrsClient {
+    config: ClientConfig,
+    client: reqwest::Client,
+    throttle: AsyncThrottle,
+}
+
+impl AsyncOpenSrsClient {
+    /// Create a new async OpenSRS client with the given configuration
+    pub fn new(config: ClientConfig) -> Self {
+        let throttle = AsyncThrottle::new(&config.throttle);
+        Self {
+            config,
+            client: reqwest::Client::new(),
+            throttle,
+        }
+    }
+
+    /// Send a command to the OpenSRS API without blocking the current thread
+    /// on the HTTP round trip. Every send waits for a concurrency/rate-limit
+    /// slot from the client's [`AsyncThrottle`], and a transient failure is
+    /// retried with exponential backoff the same way
+    /// [`OpenSrsClient::send_request`](super::client::OpenSrsClient::send_request) does.
+    pub(crate) async fn send_request<C: OpsCommand>(&self, command: &C) -> Result<C::Response> {
+        let mut attempt = 0u32;
+
+        loop {
+            let _permit = self.throttle.acquire().await;
+
+            match self.send_request_once(command).await {
+                Err(err) if self.is_retryable(&err, command.is_idempotent(), attempt) => {
+                    let delay = backoff_delay(
+                        attempt,
+                        self.config.retry.base_delay,
+                        self.config.retry.max_delay,
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(err) if attempt > 0 => {
+                    return Err(OpenSrsError::RetryExhausted {
+                        attempts: attempt + 1,
+                        source: Box::new(err),
+                    });
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Whether a failed send should be retried; mirrors
+    /// [`OpenSrsClient::is_retryable`](super::client::OpenSrsClient).
+    fn is_retryable(&self, error: &OpenSrsError, idempotent: bool, attempt: u32) -> bool {
+        let policy = &self.config.retry;
+        let retry_on = &policy.retry_on;
+
+        if attempt >= policy.max_retries {
+            return false;
+        }
+
+        if !idempotent && !retry_on.mutating_calls {
+            return false;
+        }
+
+        match error {
+            OpenSrsError::RateLimited { .. } => retry_on.rate_limit,
+            OpenSrsError::AsyncHttpError(_) => retry_on.connection_error,
+            _ => false,
+        }
+    }
+
+    async fn send_request_once<C: OpsCommand>(&self, command: &C) -> Result<C::Response> {
+        // Serialize to XML
+        let xml = serialize_request(command)?;
+
+        // Generate MD5 signature
+        let signature = generate_signature(&xml, &self.config.credential);
+
+        // Build and send HTTP request
+        let response = self
+            .client
+            .post(self.config.environment.endpoint())
+            .header("Content-Type", "text/xml")
+            .header("X-Username", &self.config.username)
+            .header("X-Signature", &signature)
+            .body(xml)
+            .send()
+            .await?;
+
+        // Parse response
+        let response_xml = response.text().await?;
+        let parsed_response: C::Response = deserialize_response(&response_xml)?;
+
+        // Check for API errors
+        if !parsed_response.is_success() {
+            return Err(classify_response_error(
+                parsed_response.response_code(),
+                parsed_response.response_text(),
+            ));
+        }
+
+        Ok(parsed_response)
+    }
+
+    /// Fetch every page of a paginated command, aggregating each page's items
+    ///
+    /// Mirrors [`OpenSrsClient::send_paginated`](super::client::OpenSrsClient), but
+    /// `await`s each page instead of blocking. Pages are still fetched one at
+    /// a time, in order — see the module docs for why.
+    pub(crate) async fn send_paginated<C>(
+        &self,
+        mut make_request: impl FnMut(u32) -> C,
+    ) -> Result<Vec<<C::Response as Paginated>::Item>>
+    where
+        C: OpsCommand,
+        C::Response: Paginated,
+    {
+        let mut all_items = Vec::new();
+        let mut page = 0u32;
+
+        loop {
+            let request = make_request(page);
+            let response = self.send_request(&request).await?;
+
+            let remainder = response.remainder();
+            all_items.extend(response.into_items());
+
+            if remainder == 0 {
+                break;
+            }
+
+            page += 1;
+        }
+
+        Ok(all_items)
+    }
+
+    /// Get domains expiring within a date range
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub async fn get_domains_by_expiredate(
+        &self,
+        exp_from: NaiveDate,
+        exp_to: NaiveDate,
+    ) -> Result<Vec<ExpiringDomain>> {
+        self.send_paginated(|page| GetDomainsByExpireDateRequest {
+            protocol: "XCP".to_string(),
+            object: "DOMAIN".to_string(),
+            action: "GET_DOMAINS_BY_EXPIREDATE".to_string(),
+            attributes: GetDomainsByExpireDateAttrs {
+                exp_from: exp_from.format("%Y-%m-%d").to_string(),
+                exp_to: exp_to.format("%Y-%m-%d").to_string(),
+                limit: Some(40),
+                page: Some(page),
+            },
+        })
+        .await
+    }
+}
@@ -0,0 +1,225 @@
+//! SQLite-backed response cache
+//!
+//! Caches the aggregated result of list-style lookups (currently
+//! [`get_domains_by_expiredate`](super::client::OpenSrsClient::get_domains_by_expiredate))
+//! keyed by their request parameters, with a TTL and a bounded entry count
+//! enforced via least-recently-used eviction, similar in spirit to a DNS
+//! resolver's bounded LRU.
+
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rusqlite::{Connection, OptionalExtension, params};
+
+use super::error::{OpenSrsError, Result};
+use super::types::ExpiringDomain;
+
+/// Configuration for the domain lookup cache
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+    /// Path to the SQLite database file (use `:memory:` for an ephemeral cache)
+    pub path: String,
+    /// How long a cached entry remains valid
+    pub ttl_secs: u64,
+    /// Maximum number of cached entries before least-recently-used eviction
+    pub max_entries: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            path: "macaw_cache.sqlite3".to_string(),
+            ttl_secs: 3600,
+            max_entries: 256,
+        }
+    }
+}
+
+/// SQLite-backed cache of [`ExpiringDomain`] lookups
+///
+/// The connection is behind a [`Mutex`] (rather than, say, one connection
+/// per thread) so [`DomainCache`] stays `Sync`: callers share one
+/// [`OpenSrsClient`](super::client::OpenSrsClient) across threads via `Arc`,
+/// and `Arc<T>: Send` requires `T: Sync`.
+pub(crate) struct DomainCache {
+    conn: Mutex<Connection>,
+    ttl_secs: u64,
+    max_entries: usize,
+}
+
+impl DomainCache {
+    pub(crate) fn open(config: &CacheConfig) -> Result<Self> {
+        let conn = Connection::open(&config.path)
+            .map_err(|e| OpenSrsError::CacheError(format!("failed to open cache database: {e}")))?;
+
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS expiring_domains_cache (
+                cache_key TEXT PRIMARY KEY,
+                payload TEXT NOT NULL,
+                expires_at INTEGER NOT NULL,
+                last_used_at INTEGER NOT NULL
+            )",
+        )
+        .map_err(|e| OpenSrsError::CacheError(format!("failed to initialize cache schema: {e}")))?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+            ttl_secs: config.ttl_secs,
+            max_entries: config.max_entries,
+        })
+    }
+
+    /// Look up a cached entry, returning `None` on a miss or an expired entry
+    pub(crate) fn get(&self, key: &str) -> Result<Option<Vec<ExpiringDomain>>> {
+        let conn = self.conn.lock().unwrap();
+
+        let row: Option<(String, i64)> = conn
+            .query_row(
+                "SELECT payload, expires_at FROM expiring_domains_cache WHERE cache_key = ?1",
+                params![key],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .map_err(|e| OpenSrsError::CacheError(e.to_string()))?;
+
+        let Some((payload, expires_at)) = row else {
+            return Ok(None);
+        };
+
+        if (expires_at as u64) <= now_secs() {
+            conn.execute(
+                "DELETE FROM expiring_domains_cache WHERE cache_key = ?1",
+                params![key],
+            )
+            .map_err(|e| OpenSrsError::CacheError(e.to_string()))?;
+            return Ok(None);
+        }
+
+        conn.execute(
+            "UPDATE expiring_domains_cache
+             SET last_used_at = (SELECT MAX(last_used_at) + 1 FROM expiring_domains_cache)
+             WHERE cache_key = ?1",
+            params![key],
+        )
+        .map_err(|e| OpenSrsError::CacheError(e.to_string()))?;
+
+        let domains = serde_json::from_str(&payload)
+            .map_err(|e| OpenSrsError::CacheError(format!("corrupt cache entry: {e}")))?;
+        Ok(Some(domains))
+    }
+
+    /// Store (or replace) the aggregate result for a request, then evict
+    /// least-recently-used entries over `max_entries`
+    pub(crate) fn put(&self, key: &str, domains: &[ExpiringDomain]) -> Result<()> {
+        let now = now_secs();
+        let payload = serde_json::to_string(domains)
+            .map_err(|e| OpenSrsError::CacheError(format!("failed to encode cache entry: {e}")))?;
+
+        // `last_used_at` is a per-cache use counter rather than a timestamp,
+        // so entries touched within the same second still have a strict
+        // recency order for eviction.
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO expiring_domains_cache (cache_key, payload, expires_at, last_used_at)
+             VALUES (?1, ?2, ?3,
+                (SELECT COALESCE(MAX(last_used_at), 0) + 1 FROM expiring_domains_cache))
+             ON CONFLICT(cache_key) DO UPDATE SET
+                payload = excluded.payload,
+                expires_at = excluded.expires_at,
+                last_used_at = excluded.last_used_at",
+            params![key, payload, (now + self.ttl_secs) as i64],
+        )
+        .map_err(|e| OpenSrsError::CacheError(e.to_string()))?;
+
+        conn.execute(
+            "DELETE FROM expiring_domains_cache WHERE cache_key NOT IN (
+                SELECT cache_key FROM expiring_domains_cache
+                ORDER BY last_used_at DESC
+                LIMIT ?1
+            )",
+            params![self.max_entries as i64],
+        )
+        .map_err(|e| OpenSrsError::CacheError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Remove a single cached entry, e.g. to force the next lookup to refetch
+    pub(crate) fn invalidate(&self, key: &str) -> Result<()> {
+        self.conn
+            .lock()
+            .unwrap()
+            .execute(
+                "DELETE FROM expiring_domains_cache WHERE cache_key = ?1",
+                params![key],
+            )
+            .map_err(|e| OpenSrsError::CacheError(e.to_string()))?;
+        Ok(())
+    }
+}
+
+/// Build the cache key for a `get_domains_by_expiredate` request
+pub(crate) fn domains_by_expiredate_key(exp_from: &str, exp_to: &str) -> String {
+    format!("get_domains_by_expiredate:{exp_from}:{exp_to}")
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory_cache(ttl_secs: u64, max_entries: usize) -> DomainCache {
+        DomainCache::open(&CacheConfig {
+            path: ":memory:".to_string(),
+            ttl_secs,
+            max_entries,
+        })
+        .unwrap()
+    }
+
+    fn sample_domain(name: &str) -> ExpiringDomain {
+        ExpiringDomain {
+            name: name.to_string(),
+            expiredate: "2026-01-01".to_string(),
+            f_auto_renew: "1".to_string(),
+            f_let_expire: "0".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_miss_then_hit() {
+        let cache = memory_cache(3600, 10);
+        assert!(cache.get("k").unwrap().is_none());
+
+        cache.put("k", &[sample_domain("example.com")]).unwrap();
+
+        let hit = cache.get("k").unwrap().unwrap();
+        assert_eq!(hit.len(), 1);
+        assert_eq!(hit[0].name, "example.com");
+    }
+
+    #[test]
+    fn test_expired_entry_is_a_miss() {
+        let cache = memory_cache(0, 10);
+        cache.put("k", &[sample_domain("example.com")]).unwrap();
+        assert!(cache.get("k").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used_over_capacity() {
+        let cache = memory_cache(3600, 2);
+        cache.put("a", &[sample_domain("a.com")]).unwrap();
+        cache.put("b", &[sample_domain("b.com")]).unwrap();
+        cache.put("c", &[sample_domain("c.com")]).unwrap();
+
+        assert!(cache.get("a").unwrap().is_none());
+        assert!(cache.get("b").unwrap().is_some());
+        assert!(cache.get("c").unwrap().is_some());
+    }
+}
@@ -1,31 +1,104 @@
 use super::auth::generate_signature;
-use super::error::Result;
-use super::types::{ClientConfig, GetDomainsByExpireDateRequest};
+use super::cache::DomainCache;
+use super::command::{OpsCommand, OpsResponse};
+use super::error::{classify_response_error, OpenSrsError, Result};
+use super::retry::backoff_delay;
+use super::throttle::Throttle;
+use super::types::ClientConfig;
 use super::xml::{deserialize_response, serialize_request};
 
 /// OpenSRS API client
 pub struct OpenSrsClient {
     config: ClientConfig,
     agent: ureq::Agent,
+    pub(crate) cache: Option<DomainCache>,
+    throttle: Throttle,
 }
 
 impl OpenSrsClient {
     /// Create a new OpenSRS client with the given configuration
-    pub fn new(config: ClientConfig) -> Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `config.cache` is set but the SQLite cache database
+    /// cannot be opened or initialized.
+    pub fn new(config: ClientConfig) -> Result<Self> {
         let agent = ureq::agent();
+        let cache = config.cache.as_ref().map(DomainCache::open).transpose()?;
+        let throttle = Throttle::new(&config.throttle);
 
-        Self { config, agent }
+        Ok(Self {
+            config,
+            agent,
+            cache,
+            throttle,
+        })
     }
 
-    /// Send a request to the OpenSRS API
+    /// Send a command to the OpenSRS API
     ///
-    /// This handles authentication, XML serialization, and response parsing.
-    pub(crate) fn send_request(
-        &self,
-        request: &GetDomainsByExpireDateRequest,
-    ) -> Result<super::types::GetDomainsByExpireDateResponse> {
+    /// This handles signing, XML serialization, transport, and response
+    /// parsing uniformly for any [`OpsCommand`], so individual actions don't
+    /// need to re-implement the request/response plumbing. Every send waits
+    /// for a concurrency/rate-limit slot from the client's [`Throttle`]. A
+    /// transient failure is retried with exponential backoff according to
+    /// the client's [`RetryPolicy`](super::retry::RetryPolicy): whether a
+    /// given failure is retryable depends on both the policy's `retry_on`
+    /// settings and whether `command` is idempotent.
+    pub(crate) fn send_request<C: OpsCommand>(&self, command: &C) -> Result<C::Response> {
+        let mut attempt = 0u32;
+
+        loop {
+            let _permit = self.throttle.acquire();
+
+            match self.send_request_once(command) {
+                Err(err) if self.is_retryable(&err, command.is_idempotent(), attempt) => {
+                    let delay = backoff_delay(
+                        attempt,
+                        self.config.retry.base_delay,
+                        self.config.retry.max_delay,
+                    );
+                    std::thread::sleep(delay);
+                    attempt += 1;
+                }
+                Err(err) if attempt > 0 => {
+                    return Err(OpenSrsError::RetryExhausted {
+                        attempts: attempt + 1,
+                        source: Box::new(err),
+                    });
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Whether a failed send should be retried, given the client's
+    /// [`RetryPolicy`](super::retry::RetryPolicy), whether the failing
+    /// command is idempotent, and how many attempts have already been made.
+    fn is_retryable(&self, error: &OpenSrsError, idempotent: bool, attempt: u32) -> bool {
+        let policy = &self.config.retry;
+        let retry_on = &policy.retry_on;
+
+        if attempt >= policy.max_retries {
+            return false;
+        }
+
+        if !idempotent && !retry_on.mutating_calls {
+            return false;
+        }
+
+        match error {
+            OpenSrsError::RateLimited { .. } => retry_on.rate_limit,
+            OpenSrsError::HttpError(_) => retry_on.connection_error,
+            #[cfg(feature = "async")]
+            OpenSrsError::AsyncHttpError(_) => retry_on.connection_error,
+            _ => false,
+        }
+    }
+
+    fn send_request_once<C: OpsCommand>(&self, command: &C) -> Result<C::Response> {
         // Serialize to XML
-        let xml = serialize_request(request)?;
+        let xml = serialize_request(command)?;
 
         // Generate MD5 signature
         let signature = generate_signature(&xml, &self.config.credential);
@@ -42,7 +115,15 @@ impl OpenSrsClient {
 
         // Parse response
         let response_xml = response.body_mut().read_to_string()?;
-        let parsed_response = deserialize_response(&response_xml)?;
+        let parsed_response: C::Response = deserialize_response(&response_xml)?;
+
+        // Check for API errors
+        if !parsed_response.is_success() {
+            return Err(classify_response_error(
+                parsed_response.response_code(),
+                parsed_response.response_text(),
+            ));
+        }
 
         Ok(parsed_response)
     }
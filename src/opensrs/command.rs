@@ -0,0 +1,68 @@
+use super::error::Result;
+use super::value::OpsValue;
+
+/// A single OpenSRS XCP command.
+///
+/// Implementors describe everything [`OpenSrsClient::send_request`] needs to
+/// build and sign a request for one API action: the `protocol`/`object`/
+/// `action` triple and the `<dt_assoc>` attribute payload. This keeps the
+/// client's signing and transport plumbing generic instead of wiring it to a
+/// single hard-coded action.
+///
+/// [`OpenSrsClient::send_request`]: super::client::OpenSrsClient::send_request
+pub(crate) trait OpsCommand {
+    /// The response type this command's action returns.
+    type Response: OpsResponse;
+
+    /// XCP protocol version string, e.g. `"XCP"`.
+    fn protocol(&self) -> &str;
+
+    /// The OpenSRS object this command targets, e.g. `"DOMAIN"`.
+    fn object(&self) -> &str;
+
+    /// The OpenSRS action name, e.g. `"GET_DOMAINS_BY_EXPIREDATE"`.
+    fn action(&self) -> &str;
+
+    /// This command's `attributes` payload, as a `dt_assoc`/`dt_array` value tree.
+    fn attributes(&self) -> OpsValue;
+
+    /// Whether retrying this command on a transient failure is safe.
+    ///
+    /// Read-only lookups are idempotent by default. Provisioning commands
+    /// that can double-submit an order (register, renew, transfer, ...)
+    /// override this to `false` so [`OpenSrsClient::send_request`] only
+    /// retries them when the client's [`RetryPolicy`] opts in via
+    /// `retry_on.mutating_calls`.
+    ///
+    /// [`OpenSrsClient::send_request`]: super::client::OpenSrsClient::send_request
+    /// [`RetryPolicy`]: super::retry::RetryPolicy
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+}
+
+/// A parsed OpenSRS response envelope.
+///
+/// Every OpenSRS reply carries the same `is_success`/`response_code`/
+/// `response_text` triple alongside action-specific attributes; implementors
+/// expose that triple so `send_request` can classify errors once instead of
+/// every call site re-deriving the same checks.
+pub(crate) trait OpsResponse: Sized {
+    /// Parse this response from the raw XML body OpenSRS returned.
+    fn parse(xml: &str) -> Result<Self>;
+
+    fn is_success(&self) -> bool;
+    fn response_code(&self) -> &str;
+    fn response_text(&self) -> &str;
+}
+
+/// A response that can be paginated by page number, e.g. list-style actions.
+///
+/// `remainder` mirrors OpenSRS's own convention: zero once all results have
+/// been returned, non-zero while more pages remain.
+pub(crate) trait Paginated {
+    type Item;
+
+    fn remainder(&self) -> u8;
+    fn into_items(self) -> Vec<Self::Item>;
+}
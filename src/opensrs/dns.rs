@@ -0,0 +1,674 @@
+use super::client::OpenSrsClient;
+use super::command::{OpsCommand, OpsResponse};
+use super::error::{OpenSrsError, Result};
+use super::value::{self, OpsValue};
+
+/// Read the `is_success`/`response_code`/`response_text` triple every
+/// OpenSRS response carries, shared by every DNS/nameserver command.
+fn parse_header(value: &OpsValue) -> (bool, String, String) {
+    let is_success = value
+        .get("is_success")
+        .and_then(OpsValue::as_scalar)
+        .is_some_and(|s| s == "1" || s.eq_ignore_ascii_case("true"));
+    let response_code = value
+        .get("response_code")
+        .and_then(OpsValue::as_scalar)
+        .unwrap_or_default()
+        .to_string();
+    let response_text = value
+        .get("response_text")
+        .and_then(OpsValue::as_scalar)
+        .unwrap_or_default()
+        .to_string();
+
+    (is_success, response_code, response_text)
+}
+
+/// A single DNS resource record in a domain's zone.
+///
+/// OpenSRS's wire format carries every record as a `type`/`subdomain`/`ttl`/
+/// `value` tuple with no type-specific fields, packing multi-part data (an
+/// MX's priority, an SRV's priority/weight/port) into `value` as
+/// space-separated text. Modelling each type as its own variant keeps that
+/// packing/unpacking in one place instead of leaking string-splitting into
+/// every caller. `Other` covers every record type this crate doesn't give a
+/// typed variant (NS, CAA, ...) so a zone containing one still round-trips
+/// instead of failing to parse outright — delegation NS records in
+/// particular are present in essentially every real zone.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DnsRecord {
+    A { name: String, ttl: u32, address: String },
+    Aaaa { name: String, ttl: u32, address: String },
+    Cname { name: String, ttl: u32, target: String },
+    Mx { name: String, ttl: u32, priority: u16, exchange: String },
+    Txt { name: String, ttl: u32, text: String },
+    Srv { name: String, ttl: u32, priority: u16, weight: u16, port: u16, target: String },
+    Other { type_str: String, name: String, ttl: u32, value: String },
+}
+
+impl DnsRecord {
+    fn type_str(&self) -> &str {
+        match self {
+            DnsRecord::A { .. } => "A",
+            DnsRecord::Aaaa { .. } => "AAAA",
+            DnsRecord::Cname { .. } => "CNAME",
+            DnsRecord::Mx { .. } => "MX",
+            DnsRecord::Txt { .. } => "TXT",
+            DnsRecord::Srv { .. } => "SRV",
+            DnsRecord::Other { type_str, .. } => type_str,
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            DnsRecord::A { name, .. }
+            | DnsRecord::Aaaa { name, .. }
+            | DnsRecord::Cname { name, .. }
+            | DnsRecord::Mx { name, .. }
+            | DnsRecord::Txt { name, .. }
+            | DnsRecord::Srv { name, .. }
+            | DnsRecord::Other { name, .. } => name,
+        }
+    }
+
+    fn ttl(&self) -> u32 {
+        match self {
+            DnsRecord::A { ttl, .. }
+            | DnsRecord::Aaaa { ttl, .. }
+            | DnsRecord::Cname { ttl, .. }
+            | DnsRecord::Mx { ttl, .. }
+            | DnsRecord::Txt { ttl, .. }
+            | DnsRecord::Srv { ttl, .. }
+            | DnsRecord::Other { ttl, .. } => *ttl,
+        }
+    }
+
+    /// Pack this record's type-specific fields into the wire `value` string.
+    fn wire_value(&self) -> String {
+        match self {
+            DnsRecord::A { address, .. } | DnsRecord::Aaaa { address, .. } => address.clone(),
+            DnsRecord::Cname { target, .. } => target.clone(),
+            DnsRecord::Mx { priority, exchange, .. } => format!("{priority} {exchange}"),
+            DnsRecord::Txt { text, .. } => text.clone(),
+            DnsRecord::Srv { priority, weight, port, target, .. } => {
+                format!("{priority} {weight} {port} {target}")
+            }
+            DnsRecord::Other { value, .. } => value.clone(),
+        }
+    }
+
+    /// Unpack a wire `type`/`subdomain`/`ttl`/`value` tuple into a typed
+    /// record, falling back to `Other` for any type this crate doesn't model
+    /// explicitly.
+    fn from_wire(type_str: &str, name: String, ttl: u32, value: &str) -> Result<Self> {
+        match type_str {
+            "A" => Ok(DnsRecord::A { name, ttl, address: value.to_string() }),
+            "AAAA" => Ok(DnsRecord::Aaaa { name, ttl, address: value.to_string() }),
+            "CNAME" => Ok(DnsRecord::Cname { name, ttl, target: value.to_string() }),
+            "MX" => {
+                let mut parts = value.splitn(2, ' ');
+                let priority = parts.next().unwrap_or_default().parse().unwrap_or(0);
+                let exchange = parts.next().unwrap_or_default().to_string();
+                Ok(DnsRecord::Mx { name, ttl, priority, exchange })
+            }
+            "TXT" => Ok(DnsRecord::Txt { name, ttl, text: value.to_string() }),
+            "SRV" => {
+                let mut parts = value.split_whitespace();
+                let priority = parts.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+                let weight = parts.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+                let port = parts.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+                let target = parts.next().unwrap_or_default().to_string();
+                Ok(DnsRecord::Srv { name, ttl, priority, weight, port, target })
+            }
+            other => Ok(DnsRecord::Other {
+                type_str: other.to_string(),
+                name,
+                ttl,
+                value: value.to_string(),
+            }),
+        }
+    }
+
+    fn to_value(&self) -> OpsValue {
+        OpsValue::Assoc(vec![
+            ("type".to_string(), OpsValue::Scalar(self.type_str().to_string())),
+            ("subdomain".to_string(), OpsValue::Scalar(self.name().to_string())),
+            ("ttl".to_string(), OpsValue::Scalar(self.ttl().to_string())),
+            ("value".to_string(), OpsValue::Scalar(self.wire_value())),
+        ])
+    }
+}
+
+struct GetDnsZoneRequest {
+    domain: String,
+}
+
+impl OpsCommand for GetDnsZoneRequest {
+    type Response = GetDnsZoneResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "DOMAIN"
+    }
+
+    fn action(&self) -> &str {
+        "GET_DNS_ZONE"
+    }
+
+    fn attributes(&self) -> OpsValue {
+        OpsValue::Assoc(vec![(
+            "domain".to_string(),
+            OpsValue::Scalar(self.domain.clone()),
+        )])
+    }
+}
+
+struct GetDnsZoneResponse {
+    is_success: bool,
+    response_code: String,
+    response_text: String,
+    records: Vec<DnsRecord>,
+}
+
+impl OpsResponse for GetDnsZoneResponse {
+    fn parse(xml: &str) -> Result<Self> {
+        value::parse_document(xml)?.try_into()
+    }
+
+    fn is_success(&self) -> bool {
+        self.is_success
+    }
+
+    fn response_code(&self) -> &str {
+        &self.response_code
+    }
+
+    fn response_text(&self) -> &str {
+        &self.response_text
+    }
+}
+
+impl TryFrom<OpsValue> for GetDnsZoneResponse {
+    type Error = OpenSrsError;
+
+    fn try_from(value: OpsValue) -> Result<Self> {
+        let (is_success, response_code, response_text) = parse_header(&value);
+
+        let wire_records = value
+            .get("attributes")
+            .and_then(|a| a.get("records"))
+            .map(OpsValue::array_items)
+            .unwrap_or_default();
+
+        let records = wire_records
+            .into_iter()
+            .map(|item| {
+                let type_str = item
+                    .get("type")
+                    .and_then(OpsValue::as_scalar)
+                    .ok_or_else(|| OpenSrsError::XmlDeserialize("DNS record missing type".to_string()))?;
+                let name = item
+                    .get("subdomain")
+                    .and_then(OpsValue::as_scalar)
+                    .unwrap_or_default()
+                    .to_string();
+                let ttl = item
+                    .get("ttl")
+                    .and_then(OpsValue::as_scalar)
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(0);
+                let record_value = item.get("value").and_then(OpsValue::as_scalar).unwrap_or_default();
+
+                DnsRecord::from_wire(type_str, name, ttl, record_value)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            is_success,
+            response_code,
+            response_text,
+            records,
+        })
+    }
+}
+
+struct SetDnsZoneRequest {
+    domain: String,
+    records: Vec<DnsRecord>,
+}
+
+impl OpsCommand for SetDnsZoneRequest {
+    type Response = SetDnsZoneResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "DOMAIN"
+    }
+
+    fn action(&self) -> &str {
+        "SET_DNS_ZONE"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
+    fn attributes(&self) -> OpsValue {
+        OpsValue::Assoc(vec![
+            ("domain".to_string(), OpsValue::Scalar(self.domain.clone())),
+            (
+                "records".to_string(),
+                OpsValue::Array(self.records.iter().map(DnsRecord::to_value).collect()),
+            ),
+        ])
+    }
+}
+
+struct SetDnsZoneResponse {
+    is_success: bool,
+    response_code: String,
+    response_text: String,
+}
+
+impl OpsResponse for SetDnsZoneResponse {
+    fn parse(xml: &str) -> Result<Self> {
+        value::parse_document(xml)?.try_into()
+    }
+
+    fn is_success(&self) -> bool {
+        self.is_success
+    }
+
+    fn response_code(&self) -> &str {
+        &self.response_code
+    }
+
+    fn response_text(&self) -> &str {
+        &self.response_text
+    }
+}
+
+impl TryFrom<OpsValue> for SetDnsZoneResponse {
+    type Error = OpenSrsError;
+
+    fn try_from(value: OpsValue) -> Result<Self> {
+        let (is_success, response_code, response_text) = parse_header(&value);
+        Ok(Self { is_success, response_code, response_text })
+    }
+}
+
+struct CreateDnsZoneRequest {
+    domain: String,
+}
+
+impl OpsCommand for CreateDnsZoneRequest {
+    type Response = SetDnsZoneResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "DOMAIN"
+    }
+
+    fn action(&self) -> &str {
+        "CREATE_DNS_ZONE"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
+    fn attributes(&self) -> OpsValue {
+        OpsValue::Assoc(vec![(
+            "domain".to_string(),
+            OpsValue::Scalar(self.domain.clone()),
+        )])
+    }
+}
+
+struct ResetDnsZoneRequest {
+    domain: String,
+}
+
+impl OpsCommand for ResetDnsZoneRequest {
+    type Response = SetDnsZoneResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "DOMAIN"
+    }
+
+    fn action(&self) -> &str {
+        "RESET_DNS_ZONE"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
+    fn attributes(&self) -> OpsValue {
+        OpsValue::Assoc(vec![(
+            "domain".to_string(),
+            OpsValue::Scalar(self.domain.clone()),
+        )])
+    }
+}
+
+/// Shared response type for the nameserver registry commands
+/// (create/delete/modify): none of them return anything beyond the usual
+/// success/code/text triple.
+struct NameserverResponse {
+    is_success: bool,
+    response_code: String,
+    response_text: String,
+}
+
+impl OpsResponse for NameserverResponse {
+    fn parse(xml: &str) -> Result<Self> {
+        value::parse_document(xml)?.try_into()
+    }
+
+    fn is_success(&self) -> bool {
+        self.is_success
+    }
+
+    fn response_code(&self) -> &str {
+        &self.response_code
+    }
+
+    fn response_text(&self) -> &str {
+        &self.response_text
+    }
+}
+
+impl TryFrom<OpsValue> for NameserverResponse {
+    type Error = OpenSrsError;
+
+    fn try_from(value: OpsValue) -> Result<Self> {
+        let (is_success, response_code, response_text) = parse_header(&value);
+        Ok(Self { is_success, response_code, response_text })
+    }
+}
+
+struct CreateNameserverRequest {
+    domain: String,
+    name: String,
+    ip_address: String,
+}
+
+impl OpsCommand for CreateNameserverRequest {
+    type Response = NameserverResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "NAMESERVER"
+    }
+
+    fn action(&self) -> &str {
+        "CREATE_NAMESERVER"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
+    fn attributes(&self) -> OpsValue {
+        OpsValue::Assoc(vec![
+            ("tld".to_string(), OpsValue::Scalar(self.domain.clone())),
+            ("name_server".to_string(), OpsValue::Scalar(self.name.clone())),
+            ("ip".to_string(), OpsValue::Scalar(self.ip_address.clone())),
+        ])
+    }
+}
+
+struct DeleteNameserverRequest {
+    domain: String,
+    name: String,
+}
+
+impl OpsCommand for DeleteNameserverRequest {
+    type Response = NameserverResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "NAMESERVER"
+    }
+
+    fn action(&self) -> &str {
+        "DELETE_NAMESERVER"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
+    fn attributes(&self) -> OpsValue {
+        OpsValue::Assoc(vec![
+            ("tld".to_string(), OpsValue::Scalar(self.domain.clone())),
+            ("name_server".to_string(), OpsValue::Scalar(self.name.clone())),
+        ])
+    }
+}
+
+struct ModifyNameserverRequest {
+    domain: String,
+    name: String,
+    ip_address: String,
+}
+
+impl OpsCommand for ModifyNameserverRequest {
+    type Response = NameserverResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "NAMESERVER"
+    }
+
+    fn action(&self) -> &str {
+        "MODIFY_NAMESERVER"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
+    fn attributes(&self) -> OpsValue {
+        OpsValue::Assoc(vec![
+            ("tld".to_string(), OpsValue::Scalar(self.domain.clone())),
+            ("name_server".to_string(), OpsValue::Scalar(self.name.clone())),
+            ("ip".to_string(), OpsValue::Scalar(self.ip_address.clone())),
+        ])
+    }
+}
+
+impl OpenSrsClient {
+    /// Fetch a domain's current DNS zone records.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn get_dns_zone(&self, domain: &str) -> Result<Vec<DnsRecord>> {
+        let request = GetDnsZoneRequest {
+            domain: domain.to_string(),
+        };
+
+        let response = self.send_request(&request)?;
+        Ok(response.records)
+    }
+
+    /// Replace a domain's DNS zone with the given records.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn set_dns_zone(&self, domain: &str, records: Vec<DnsRecord>) -> Result<()> {
+        let request = SetDnsZoneRequest {
+            domain: domain.to_string(),
+            records,
+        };
+
+        self.send_request(&request)?;
+        Ok(())
+    }
+
+    /// Create a fresh DNS zone for a domain, with the registry's default
+    /// records (SOA and nameservers) and nothing else.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn create_dns_zone(&self, domain: &str) -> Result<()> {
+        let request = CreateDnsZoneRequest {
+            domain: domain.to_string(),
+        };
+
+        self.send_request(&request)?;
+        Ok(())
+    }
+
+    /// Discard a domain's DNS zone and recreate it with the registry's
+    /// default records.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn reset_dns_zone(&self, domain: &str) -> Result<()> {
+        let request = ResetDnsZoneRequest {
+            domain: domain.to_string(),
+        };
+
+        self.send_request(&request)?;
+        Ok(())
+    }
+
+    /// Register a nameserver host (e.g. a glue record) under a domain.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn create_nameserver(&self, domain: &str, name: &str, ip_address: &str) -> Result<()> {
+        let request = CreateNameserverRequest {
+            domain: domain.to_string(),
+            name: name.to_string(),
+            ip_address: ip_address.to_string(),
+        };
+
+        self.send_request(&request)?;
+        Ok(())
+    }
+
+    /// Remove a previously-registered nameserver host from a domain.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn delete_nameserver(&self, domain: &str, name: &str) -> Result<()> {
+        let request = DeleteNameserverRequest {
+            domain: domain.to_string(),
+            name: name.to_string(),
+        };
+
+        self.send_request(&request)?;
+        Ok(())
+    }
+
+    /// Update the IP address of a registered nameserver host.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn modify_nameserver(&self, domain: &str, name: &str, ip_address: &str) -> Result<()> {
+        let request = ModifyNameserverRequest {
+            domain: domain.to_string(),
+            name: name.to_string(),
+            ip_address: ip_address.to_string(),
+        };
+
+        self.send_request(&request)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(record: DnsRecord) -> DnsRecord {
+        let wire = record.to_value();
+        let type_str = wire.get("type").and_then(OpsValue::as_scalar).unwrap();
+        let name = wire.get("subdomain").and_then(OpsValue::as_scalar).unwrap().to_string();
+        let ttl: u32 = wire.get("ttl").and_then(OpsValue::as_scalar).unwrap().parse().unwrap();
+        let value = wire.get("value").and_then(OpsValue::as_scalar).unwrap();
+        DnsRecord::from_wire(type_str, name, ttl, value).unwrap()
+    }
+
+    #[test]
+    fn test_roundtrips_each_typed_variant() {
+        let records = vec![
+            DnsRecord::A { name: "www".to_string(), ttl: 300, address: "1.2.3.4".to_string() },
+            DnsRecord::Aaaa { name: "www".to_string(), ttl: 300, address: "::1".to_string() },
+            DnsRecord::Cname {
+                name: "blog".to_string(),
+                ttl: 300,
+                target: "example.com".to_string(),
+            },
+            DnsRecord::Mx {
+                name: "@".to_string(),
+                ttl: 3600,
+                priority: 10,
+                exchange: "mail.example.com".to_string(),
+            },
+            DnsRecord::Txt {
+                name: "@".to_string(),
+                ttl: 300,
+                text: "v=spf1 -all".to_string(),
+            },
+            DnsRecord::Srv {
+                name: "_sip._tcp".to_string(),
+                ttl: 300,
+                priority: 10,
+                weight: 20,
+                port: 5060,
+                target: "sip.example.com".to_string(),
+            },
+        ];
+
+        for record in records {
+            assert_eq!(roundtrip(record.clone()), record);
+        }
+    }
+
+    #[test]
+    fn test_unrecognized_record_type_falls_back_to_other_instead_of_erroring() {
+        let record = DnsRecord::from_wire("NS", "@".to_string(), 86400, "ns1.example.com").unwrap();
+        assert_eq!(
+            record,
+            DnsRecord::Other {
+                type_str: "NS".to_string(),
+                name: "@".to_string(),
+                ttl: 86400,
+                value: "ns1.example.com".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use super::super::client::OpenSrsClient;
+use super::super::command::{OpsCommand, OpsResponse};
+use super::super::error::{OpenSrsError, Result};
+use super::super::value::{self, OpsValue};
+use super::shared::parse_response_header;
+
+/// Availability and pricing for a domain, as reported by `check_domain`
+#[derive(Debug, Clone)]
+pub struct DomainAvailability {
+    pub available: bool,
+    pub status: String,
+    pub price: Option<String>,
+}
+
+struct CheckDomainRequest {
+    domain: String,
+}
+
+impl OpsCommand for CheckDomainRequest {
+    type Response = CheckDomainResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "DOMAIN"
+    }
+
+    fn action(&self) -> &str {
+        "LOOKUP"
+    }
+
+    fn attributes(&self) -> OpsValue {
+        OpsValue::Assoc(vec![(
+            "domain".to_string(),
+            OpsValue::Scalar(self.domain.clone()),
+        )])
+    }
+}
+
+struct CheckDomainResponse {
+    is_success: bool,
+    response_code: String,
+    response_text: String,
+    availability: DomainAvailability,
+}
+
+impl OpsResponse for CheckDomainResponse {
+    fn parse(xml: &str) -> Result<Self> {
+        value::parse_document(xml)?.try_into()
+    }
+
+    fn is_success(&self) -> bool {
+        self.is_success
+    }
+
+    fn response_code(&self) -> &str {
+        &self.response_code
+    }
+
+    fn response_text(&self) -> &str {
+        &self.response_text
+    }
+}
+
+impl TryFrom<OpsValue> for CheckDomainResponse {
+    type Error = OpenSrsError;
+
+    fn try_from(value: OpsValue) -> Result<Self> {
+        let (is_success, response_code, response_text) = parse_response_header(&value);
+        let attrs = value.get("attributes");
+
+        let status = attrs
+            .and_then(|a| a.get("status"))
+            .and_then(OpsValue::as_scalar)
+            .unwrap_or_default()
+            .to_string();
+        let price = attrs
+            .and_then(|a| a.get("price"))
+            .and_then(OpsValue::as_scalar)
+            .map(str::to_string);
+
+        Ok(Self {
+            is_success,
+            response_code,
+            response_text,
+            availability: DomainAvailability {
+                available: status.eq_ignore_ascii_case("available"),
+                status,
+                price,
+            },
+        })
+    }
+}
+
+impl OpenSrsClient {
+    /// Check whether a domain is available for registration, and its price
+    /// if OpenSRS reports one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn check_domain(&self, domain: &str) -> Result<DomainAvailability> {
+        let request = CheckDomainRequest {
+            domain: domain.to_string(),
+        };
+
+        let response = self.send_request(&request)?;
+        Ok(response.availability)
+    }
+}
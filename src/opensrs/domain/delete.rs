@@ -0,0 +1,90 @@
+use super::super::client::OpenSrsClient;
+use super::super::command::{OpsCommand, OpsResponse};
+use super::super::error::{OpenSrsError, Result};
+use super::super::value::{self, OpsValue};
+use super::shared::parse_response_header;
+
+struct DeleteDomainRequest {
+    domain: String,
+}
+
+impl OpsCommand for DeleteDomainRequest {
+    type Response = DeleteDomainResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "DOMAIN"
+    }
+
+    fn action(&self) -> &str {
+        "DELETE"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
+    fn attributes(&self) -> OpsValue {
+        OpsValue::Assoc(vec![(
+            "domain".to_string(),
+            OpsValue::Scalar(self.domain.clone()),
+        )])
+    }
+}
+
+struct DeleteDomainResponse {
+    is_success: bool,
+    response_code: String,
+    response_text: String,
+}
+
+impl OpsResponse for DeleteDomainResponse {
+    fn parse(xml: &str) -> Result<Self> {
+        value::parse_document(xml)?.try_into()
+    }
+
+    fn is_success(&self) -> bool {
+        self.is_success
+    }
+
+    fn response_code(&self) -> &str {
+        &self.response_code
+    }
+
+    fn response_text(&self) -> &str {
+        &self.response_text
+    }
+}
+
+impl TryFrom<OpsValue> for DeleteDomainResponse {
+    type Error = OpenSrsError;
+
+    fn try_from(value: OpsValue) -> Result<Self> {
+        let (is_success, response_code, response_text) = parse_response_header(&value);
+
+        Ok(Self {
+            is_success,
+            response_code,
+            response_text,
+        })
+    }
+}
+
+impl OpenSrsClient {
+    /// Delete a domain from the account.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn delete_domain(&self, domain: &str) -> Result<()> {
+        let request = DeleteDomainRequest {
+            domain: domain.to_string(),
+        };
+
+        self.send_request(&request)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use super::super::cache::domains_by_expiredate_key;
+use super::super::client::OpenSrsClient;
+use super::super::command::{OpsCommand, Paginated};
+use super::super::error::Result;
+use super::super::types::{
+    ExpiringDomain, GetDomainsByExpireDateAttrs, GetDomainsByExpireDateRequest,
+};
+use chrono::NaiveDate;
+
+impl OpenSrsClient {
+    /// Get domains expiring within a date range
+    ///
+    /// This method automatically handles pagination and returns all matching
+    /// domains. If a response cache is configured, a fresh cached result for
+    /// this exact date range is returned without hitting the API; on a miss,
+    /// every page is fetched and the aggregate is cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `exp_from` - Start date for expiration range (inclusive)
+    /// * `exp_to` - End date for expiration range (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// A vector of all domains expiring within the specified date range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn get_domains_by_expiredate(
+        &self,
+        exp_from: NaiveDate,
+        exp_to: NaiveDate,
+    ) -> Result<Vec<ExpiringDomain>> {
+        let key = domains_by_expiredate_key(
+            &exp_from.format("%Y-%m-%d").to_string(),
+            &exp_to.format("%Y-%m-%d").to_string(),
+        );
+
+        if let Some(cache) = &self.cache {
+            if let Some(cached) = cache.get(&key)? {
+                return Ok(cached);
+            }
+        }
+
+        self.refresh_domains_by_expiredate(exp_from, exp_to)
+    }
+
+    /// Force-refresh domains expiring within a date range, bypassing and then
+    /// repopulating the response cache (if configured)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn refresh_domains_by_expiredate(
+        &self,
+        exp_from: NaiveDate,
+        exp_to: NaiveDate,
+    ) -> Result<Vec<ExpiringDomain>> {
+        let domains = self.send_paginated(|page| GetDomainsByExpireDateRequest {
+            protocol: "XCP".to_string(),
+            object: "DOMAIN".to_string(),
+            action: "GET_DOMAINS_BY_EXPIREDATE".to_string(),
+            attributes: GetDomainsByExpireDateAttrs {
+                exp_from: exp_from.format("%Y-%m-%d").to_string(),
+                exp_to: exp_to.format("%Y-%m-%d").to_string(),
+                limit: Some(40), // Default page size
+                page: Some(page),
+            },
+        })?;
+
+        if let Some(cache) = &self.cache {
+            let key = domains_by_expiredate_key(
+                &exp_from.format("%Y-%m-%d").to_string(),
+                &exp_to.format("%Y-%m-%d").to_string(),
+            );
+            cache.put(&key, &domains)?;
+        }
+
+        Ok(domains)
+    }
+
+    /// Fetch every page of a paginated command, aggregating each page's items
+    ///
+    /// `make_request` builds the command for a given zero-based page number.
+    /// This drives it until the response's `remainder` reports no pages
+    /// remain, so any list-style action gets automatic page aggregation for
+    /// free by implementing [`Paginated`] on its response.
+    pub(crate) fn send_paginated<C>(
+        &self,
+        mut make_request: impl FnMut(u32) -> C,
+    ) -> Result<Vec<<C::Response as Paginated>::Item>>
+    where
+        C: OpsCommand,
+        C::Response: Paginated,
+    {
+        let mut all_items = Vec::new();
+        let mut page = 0u32;
+
+        loop {
+            let request = make_request(page);
+            let response = self.send_request(&request)?;
+
+            let remainder = response.remainder();
+            all_items.extend(response.into_items());
+
+            if remainder == 0 {
+                break;
+            }
+
+            page += 1;
+        }
+
+        Ok(all_items)
+    }
+}
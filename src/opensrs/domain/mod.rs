@@ -0,0 +1,21 @@
+//! Domain lifecycle operations: expiry lookups, availability checks, and
+//! the registrar commands (register, renew, transfer, redeem, delete).
+//!
+//! Each command gets its own submodule with its request/response types and
+//! the [`super::client::OpenSrsClient`] method(s) that drive it; shared
+//! plumbing (contact/order types, response-header parsing) lives in
+//! [`shared`].
+
+mod check;
+mod delete;
+mod expiry;
+mod redeem;
+mod register;
+mod renew;
+mod shared;
+mod transfer;
+
+pub use check::DomainAvailability;
+pub use register::{RegistrationOptions, RegistrationOptionsBuilder};
+pub use shared::{ContactInfo, ContactInfoBuilder, ContactSet, ContactSetBuilder, OrderReceipt};
+pub use transfer::TransferStatus;
@@ -0,0 +1,54 @@
+use super::super::client::OpenSrsClient;
+use super::super::command::OpsCommand;
+use super::super::error::Result;
+use super::super::value::OpsValue;
+use super::shared::{OrderReceipt, OrderResponse};
+
+/// Redeem a domain that has entered the registry's redemption grace period
+/// after deletion.
+struct RedeemDomainRequest {
+    domain: String,
+}
+
+impl OpsCommand for RedeemDomainRequest {
+    type Response = OrderResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "DOMAIN"
+    }
+
+    fn action(&self) -> &str {
+        "REDEEM"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
+    fn attributes(&self) -> OpsValue {
+        OpsValue::Assoc(vec![(
+            "domain".to_string(),
+            OpsValue::Scalar(self.domain.clone()),
+        )])
+    }
+}
+
+impl OpenSrsClient {
+    /// Redeem a domain currently in the registry's redemption grace period.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn redeem_domain(&self, domain: &str) -> Result<OrderReceipt> {
+        let request = RedeemDomainRequest {
+            domain: domain.to_string(),
+        };
+
+        let response = self.send_request(&request)?;
+        Ok(response.receipt)
+    }
+}
@@ -0,0 +1,144 @@
+use super::super::client::OpenSrsClient;
+use super::super::command::OpsCommand;
+use super::super::error::Result;
+use super::super::value::OpsValue;
+use super::shared::{ContactSet, OrderReceipt, OrderResponse};
+
+/// Default registration period, in years, when a [`RegistrationOptionsBuilder`]
+/// doesn't specify one.
+const DEFAULT_PERIOD_YEARS: u32 = 1;
+
+/// The parameters needed to register a new domain: contact set, nameserver
+/// list, registration period, and auto-renew flag.
+///
+/// Built via [`RegistrationOptions::builder`].
+#[derive(Debug, Clone)]
+pub struct RegistrationOptions {
+    pub contact_set: ContactSet,
+    pub nameservers: Vec<String>,
+    pub period_years: u32,
+    pub auto_renew: bool,
+}
+
+impl RegistrationOptions {
+    /// Start building registration options for the given contact set.
+    pub fn builder(contact_set: ContactSet) -> RegistrationOptionsBuilder {
+        RegistrationOptionsBuilder {
+            contact_set,
+            nameservers: Vec::new(),
+            period_years: DEFAULT_PERIOD_YEARS,
+            auto_renew: false,
+        }
+    }
+}
+
+/// Builder for [`RegistrationOptions`]
+#[derive(Debug, Clone)]
+pub struct RegistrationOptionsBuilder {
+    contact_set: ContactSet,
+    nameservers: Vec<String>,
+    period_years: u32,
+    auto_renew: bool,
+}
+
+impl RegistrationOptionsBuilder {
+    /// Set the registration period, in years (defaults to 1).
+    pub fn period_years(mut self, period_years: u32) -> Self {
+        self.period_years = period_years;
+        self
+    }
+
+    /// Enable or disable auto-renew (defaults to disabled).
+    pub fn auto_renew(mut self, auto_renew: bool) -> Self {
+        self.auto_renew = auto_renew;
+        self
+    }
+
+    /// Append a single nameserver to the registration's nameserver list.
+    pub fn nameserver(mut self, nameserver: impl Into<String>) -> Self {
+        self.nameservers.push(nameserver.into());
+        self
+    }
+
+    /// Replace the registration's nameserver list.
+    pub fn nameservers(mut self, nameservers: impl IntoIterator<Item = String>) -> Self {
+        self.nameservers = nameservers.into_iter().collect();
+        self
+    }
+
+    /// Finish building the registration options.
+    pub fn build(self) -> RegistrationOptions {
+        RegistrationOptions {
+            contact_set: self.contact_set,
+            nameservers: self.nameservers,
+            period_years: self.period_years,
+            auto_renew: self.auto_renew,
+        }
+    }
+}
+
+struct RegisterDomainRequest {
+    domain: String,
+    options: RegistrationOptions,
+}
+
+impl OpsCommand for RegisterDomainRequest {
+    type Response = OrderResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "DOMAIN"
+    }
+
+    fn action(&self) -> &str {
+        "SW_REGISTER"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
+    fn attributes(&self) -> OpsValue {
+        let nameservers = self
+            .options
+            .nameservers
+            .iter()
+            .map(|ns| OpsValue::Scalar(ns.clone()))
+            .collect();
+
+        OpsValue::Assoc(vec![
+            ("domain".to_string(), OpsValue::Scalar(self.domain.clone())),
+            (
+                "period".to_string(),
+                OpsValue::Scalar(self.options.period_years.to_string()),
+            ),
+            (
+                "auto_renew".to_string(),
+                OpsValue::Scalar(if self.options.auto_renew { "1" } else { "0" }.to_string()),
+            ),
+            ("reg_type".to_string(), OpsValue::Scalar("new".to_string())),
+            ("nameserver_list".to_string(), OpsValue::Array(nameservers)),
+            ("contact_set".to_string(), self.options.contact_set.to_value()),
+        ])
+    }
+}
+
+impl OpenSrsClient {
+    /// Register a new domain with the given [`RegistrationOptions`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn register_domain(&self, domain: &str, options: RegistrationOptions) -> Result<OrderReceipt> {
+        let request = RegisterDomainRequest {
+            domain: domain.to_string(),
+            options,
+        };
+
+        let response = self.send_request(&request)?;
+        Ok(response.receipt)
+    }
+}
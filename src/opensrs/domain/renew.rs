@@ -0,0 +1,68 @@
+use super::super::client::OpenSrsClient;
+use super::super::command::OpsCommand;
+use super::super::error::Result;
+use super::super::value::OpsValue;
+use super::shared::{OrderReceipt, OrderResponse};
+
+struct RenewDomainRequest {
+    domain: String,
+    period_years: u32,
+    current_expiration_year: u32,
+}
+
+impl OpsCommand for RenewDomainRequest {
+    type Response = OrderResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "DOMAIN"
+    }
+
+    fn action(&self) -> &str {
+        "RENEW"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
+    fn attributes(&self) -> OpsValue {
+        OpsValue::Assoc(vec![
+            ("domain".to_string(), OpsValue::Scalar(self.domain.clone())),
+            ("period".to_string(), OpsValue::Scalar(self.period_years.to_string())),
+            (
+                "currentexpirationyear".to_string(),
+                OpsValue::Scalar(self.current_expiration_year.to_string()),
+            ),
+        ])
+    }
+}
+
+impl OpenSrsClient {
+    /// Renew a domain for the given number of years.
+    ///
+    /// `current_expiration_year` must match the year OpenSRS has on file, as
+    /// a safeguard against accidental double-renewal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn renew_domain(
+        &self,
+        domain: &str,
+        period_years: u32,
+        current_expiration_year: u32,
+    ) -> Result<OrderReceipt> {
+        let request = RenewDomainRequest {
+            domain: domain.to_string(),
+            period_years,
+            current_expiration_year,
+        };
+
+        let response = self.send_request(&request)?;
+        Ok(response.receipt)
+    }
+}
@@ -0,0 +1,349 @@
+use super::super::command::OpsResponse;
+use super::super::error::{OpenSrsError, Result};
+use super::super::value::{self, OpsValue};
+
+/// Read the `is_success`/`response_code`/`response_text` triple every
+/// OpenSRS response carries, shared by every lifecycle command.
+pub(super) fn parse_response_header(value: &OpsValue) -> (bool, String, String) {
+    let is_success = value
+        .get("is_success")
+        .and_then(OpsValue::as_scalar)
+        .is_some_and(|s| s == "1" || s.eq_ignore_ascii_case("true"));
+    let response_code = value
+        .get("response_code")
+        .and_then(OpsValue::as_scalar)
+        .unwrap_or_default()
+        .to_string();
+    let response_text = value
+        .get("response_text")
+        .and_then(OpsValue::as_scalar)
+        .unwrap_or_default()
+        .to_string();
+
+    (is_success, response_code, response_text)
+}
+
+/// A contact record (registrant, admin, tech, or billing) required by
+/// registry policy when registering or transferring a domain.
+#[derive(Debug, Clone)]
+pub struct ContactInfo {
+    pub first_name: String,
+    pub last_name: String,
+    pub org_name: String,
+    pub email: String,
+    pub phone: String,
+    pub address1: String,
+    pub city: String,
+    pub state: String,
+    pub country: String,
+    pub postal_code: String,
+}
+
+impl ContactInfo {
+    /// Start building a contact record.
+    pub fn builder() -> ContactInfoBuilder {
+        ContactInfoBuilder::default()
+    }
+
+    pub(super) fn to_value(&self) -> OpsValue {
+        OpsValue::Assoc(vec![
+            ("first_name".to_string(), OpsValue::Scalar(self.first_name.clone())),
+            ("last_name".to_string(), OpsValue::Scalar(self.last_name.clone())),
+            ("org_name".to_string(), OpsValue::Scalar(self.org_name.clone())),
+            ("email".to_string(), OpsValue::Scalar(self.email.clone())),
+            ("phone".to_string(), OpsValue::Scalar(self.phone.clone())),
+            ("address1".to_string(), OpsValue::Scalar(self.address1.clone())),
+            ("city".to_string(), OpsValue::Scalar(self.city.clone())),
+            ("state".to_string(), OpsValue::Scalar(self.state.clone())),
+            ("country".to_string(), OpsValue::Scalar(self.country.clone())),
+            (
+                "postal_code".to_string(),
+                OpsValue::Scalar(self.postal_code.clone()),
+            ),
+        ])
+    }
+}
+
+/// Builder for [`ContactInfo`]. Every field defaults to an empty string, so
+/// callers only need to set the fields the registry actually requires for
+/// their use case.
+#[derive(Debug, Clone, Default)]
+pub struct ContactInfoBuilder {
+    first_name: String,
+    last_name: String,
+    org_name: String,
+    email: String,
+    phone: String,
+    address1: String,
+    city: String,
+    state: String,
+    country: String,
+    postal_code: String,
+}
+
+impl ContactInfoBuilder {
+    pub fn first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.first_name = first_name.into();
+        self
+    }
+
+    pub fn last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.last_name = last_name.into();
+        self
+    }
+
+    pub fn org_name(mut self, org_name: impl Into<String>) -> Self {
+        self.org_name = org_name.into();
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.email = email.into();
+        self
+    }
+
+    pub fn phone(mut self, phone: impl Into<String>) -> Self {
+        self.phone = phone.into();
+        self
+    }
+
+    pub fn address1(mut self, address1: impl Into<String>) -> Self {
+        self.address1 = address1.into();
+        self
+    }
+
+    pub fn city(mut self, city: impl Into<String>) -> Self {
+        self.city = city.into();
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.state = state.into();
+        self
+    }
+
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.country = country.into();
+        self
+    }
+
+    pub fn postal_code(mut self, postal_code: impl Into<String>) -> Self {
+        self.postal_code = postal_code.into();
+        self
+    }
+
+    /// Finish building the contact record.
+    pub fn build(self) -> ContactInfo {
+        ContactInfo {
+            first_name: self.first_name,
+            last_name: self.last_name,
+            org_name: self.org_name,
+            email: self.email,
+            phone: self.phone,
+            address1: self.address1,
+            city: self.city,
+            state: self.state,
+            country: self.country,
+            postal_code: self.postal_code,
+        }
+    }
+}
+
+/// The full set of contacts a registration or transfer must supply.
+#[derive(Debug, Clone)]
+pub struct ContactSet {
+    pub owner: ContactInfo,
+    pub admin: ContactInfo,
+    pub tech: ContactInfo,
+    pub billing: ContactInfo,
+}
+
+impl ContactSet {
+    /// Start building a contact set from its mandatory owner contact; admin,
+    /// tech, and billing all default to a clone of the owner, since
+    /// registrants overwhelmingly reuse the same contact for every role.
+    pub fn builder(owner: ContactInfo) -> ContactSetBuilder {
+        ContactSetBuilder {
+            admin: owner.clone(),
+            tech: owner.clone(),
+            billing: owner.clone(),
+            owner,
+        }
+    }
+
+    pub(super) fn to_value(&self) -> OpsValue {
+        OpsValue::Assoc(vec![
+            ("owner".to_string(), self.owner.to_value()),
+            ("admin".to_string(), self.admin.to_value()),
+            ("tech".to_string(), self.tech.to_value()),
+            ("billing".to_string(), self.billing.to_value()),
+        ])
+    }
+}
+
+/// Builder for [`ContactSet`]. Admin, tech, and billing default to a clone
+/// of the owner contact passed to [`ContactSet::builder`], so callers only
+/// override the roles that actually differ from the owner.
+#[derive(Debug, Clone)]
+pub struct ContactSetBuilder {
+    owner: ContactInfo,
+    admin: ContactInfo,
+    tech: ContactInfo,
+    billing: ContactInfo,
+}
+
+impl ContactSetBuilder {
+    pub fn admin(mut self, contact: ContactInfo) -> Self {
+        self.admin = contact;
+        self
+    }
+
+    pub fn tech(mut self, contact: ContactInfo) -> Self {
+        self.tech = contact;
+        self
+    }
+
+    pub fn billing(mut self, contact: ContactInfo) -> Self {
+        self.billing = contact;
+        self
+    }
+
+    /// Finish building the contact set.
+    pub fn build(self) -> ContactSet {
+        ContactSet {
+            owner: self.owner,
+            admin: self.admin,
+            tech: self.tech,
+            billing: self.billing,
+        }
+    }
+}
+
+/// The outcome of a registrar order (registration, renewal, transfer, or
+/// redemption)
+#[derive(Debug, Clone)]
+pub struct OrderReceipt {
+    pub order_id: String,
+    pub admin_email: String,
+}
+
+impl TryFrom<OpsValue> for OrderReceipt {
+    type Error = OpenSrsError;
+
+    fn try_from(value: OpsValue) -> Result<Self> {
+        let attrs = value.get("attributes");
+        let field = |key: &str| {
+            attrs
+                .and_then(|a| a.get(key))
+                .and_then(OpsValue::as_scalar)
+                .unwrap_or_default()
+                .to_string()
+        };
+
+        Ok(Self {
+            order_id: field("id"),
+            admin_email: field("admin_email"),
+        })
+    }
+}
+
+/// Shared response type for every command that resolves to an order:
+/// register, renew, transfer, process_transfer, cancel_transfer, and
+/// redeem all return an order id and admin email on success.
+pub(super) struct OrderResponse {
+    is_success: bool,
+    response_code: String,
+    response_text: String,
+    pub(super) receipt: OrderReceipt,
+}
+
+impl OpsResponse for OrderResponse {
+    fn parse(xml: &str) -> Result<Self> {
+        value::parse_document(xml)?.try_into()
+    }
+
+    fn is_success(&self) -> bool {
+        self.is_success
+    }
+
+    fn response_code(&self) -> &str {
+        &self.response_code
+    }
+
+    fn response_text(&self) -> &str {
+        &self.response_text
+    }
+}
+
+impl TryFrom<OpsValue> for OrderResponse {
+    type Error = OpenSrsError;
+
+    fn try_from(value: OpsValue) -> Result<Self> {
+        let (is_success, response_code, response_text) = parse_response_header(&value);
+        let receipt = OrderReceipt::try_from(value)?;
+
+        Ok(Self {
+            is_success,
+            response_code,
+            response_text,
+            receipt,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contact_info_builder_sets_every_field() {
+        let contact = ContactInfo::builder()
+            .first_name("Jane")
+            .last_name("Doe")
+            .org_name("Example Org")
+            .email("jane@example.com")
+            .phone("+1.5551234567")
+            .address1("1 Example St")
+            .city("Example City")
+            .state("EX")
+            .country("US")
+            .postal_code("00000")
+            .build();
+
+        assert_eq!(contact.first_name, "Jane");
+        assert_eq!(contact.last_name, "Doe");
+        assert_eq!(contact.org_name, "Example Org");
+        assert_eq!(contact.email, "jane@example.com");
+        assert_eq!(contact.phone, "+1.5551234567");
+        assert_eq!(contact.address1, "1 Example St");
+        assert_eq!(contact.city, "Example City");
+        assert_eq!(contact.state, "EX");
+        assert_eq!(contact.country, "US");
+        assert_eq!(contact.postal_code, "00000");
+    }
+
+    #[test]
+    fn test_contact_set_builder_defaults_admin_tech_billing_to_owner() {
+        let owner = ContactInfo::builder().email("owner@example.com").build();
+
+        let set = ContactSet::builder(owner.clone()).build();
+
+        assert_eq!(set.owner.email, owner.email);
+        assert_eq!(set.admin.email, owner.email);
+        assert_eq!(set.tech.email, owner.email);
+        assert_eq!(set.billing.email, owner.email);
+    }
+
+    #[test]
+    fn test_contact_set_builder_overrides_take_effect() {
+        let owner = ContactInfo::builder().email("owner@example.com").build();
+        let admin = ContactInfo::builder().email("admin@example.com").build();
+
+        let set = ContactSet::builder(owner.clone()).admin(admin.clone()).build();
+
+        assert_eq!(set.owner.email, owner.email);
+        assert_eq!(set.admin.email, admin.email);
+        assert_eq!(set.tech.email, owner.email);
+        assert_eq!(set.billing.email, owner.email);
+    }
+}
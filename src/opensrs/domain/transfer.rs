@@ -0,0 +1,267 @@
+use super::super::client::OpenSrsClient;
+use super::super::command::{OpsCommand, OpsResponse};
+use super::super::error::{OpenSrsError, Result};
+use super::super::value::{self, OpsValue};
+use super::shared::{parse_response_header, OrderReceipt, OrderResponse};
+
+/// The state of a pending or completed inbound transfer
+#[derive(Debug, Clone)]
+pub struct TransferStatus {
+    pub status: String,
+    pub pending_reason: Option<String>,
+}
+
+struct TransferDomainRequest {
+    domain: String,
+    auth_code: String,
+    nameservers: Vec<String>,
+}
+
+impl OpsCommand for TransferDomainRequest {
+    type Response = OrderResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "DOMAIN"
+    }
+
+    fn action(&self) -> &str {
+        "TRANSFER"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
+    fn attributes(&self) -> OpsValue {
+        let nameservers = self
+            .nameservers
+            .iter()
+            .map(|ns| OpsValue::Scalar(ns.clone()))
+            .collect();
+
+        OpsValue::Assoc(vec![
+            ("domain".to_string(), OpsValue::Scalar(self.domain.clone())),
+            ("auth_info".to_string(), OpsValue::Scalar(self.auth_code.clone())),
+            ("nameserver_list".to_string(), OpsValue::Array(nameservers)),
+        ])
+    }
+}
+
+struct GetTransferStatusRequest {
+    domain: String,
+}
+
+impl OpsCommand for GetTransferStatusRequest {
+    type Response = GetTransferStatusResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "DOMAIN"
+    }
+
+    fn action(&self) -> &str {
+        "GET_TRANSFER_STATUS"
+    }
+
+    fn attributes(&self) -> OpsValue {
+        OpsValue::Assoc(vec![(
+            "domain".to_string(),
+            OpsValue::Scalar(self.domain.clone()),
+        )])
+    }
+}
+
+struct GetTransferStatusResponse {
+    is_success: bool,
+    response_code: String,
+    response_text: String,
+    status: TransferStatus,
+}
+
+impl OpsResponse for GetTransferStatusResponse {
+    fn parse(xml: &str) -> Result<Self> {
+        value::parse_document(xml)?.try_into()
+    }
+
+    fn is_success(&self) -> bool {
+        self.is_success
+    }
+
+    fn response_code(&self) -> &str {
+        &self.response_code
+    }
+
+    fn response_text(&self) -> &str {
+        &self.response_text
+    }
+}
+
+impl TryFrom<OpsValue> for GetTransferStatusResponse {
+    type Error = OpenSrsError;
+
+    fn try_from(value: OpsValue) -> Result<Self> {
+        let (is_success, response_code, response_text) = parse_response_header(&value);
+        let attrs = value.get("attributes");
+
+        let status = attrs
+            .and_then(|a| a.get("transfer_status"))
+            .and_then(OpsValue::as_scalar)
+            .unwrap_or_default()
+            .to_string();
+        let pending_reason = attrs
+            .and_then(|a| a.get("pending_reason"))
+            .and_then(OpsValue::as_scalar)
+            .map(str::to_string);
+
+        Ok(Self {
+            is_success,
+            response_code,
+            response_text,
+            status: TransferStatus {
+                status,
+                pending_reason,
+            },
+        })
+    }
+}
+
+struct ProcessTransferRequest {
+    domain: String,
+    accept: bool,
+}
+
+impl OpsCommand for ProcessTransferRequest {
+    type Response = OrderResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "TRANSFER"
+    }
+
+    fn action(&self) -> &str {
+        "PROCESS_TRANSFER"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
+    fn attributes(&self) -> OpsValue {
+        OpsValue::Assoc(vec![
+            ("domain".to_string(), OpsValue::Scalar(self.domain.clone())),
+            (
+                "action".to_string(),
+                OpsValue::Scalar(if self.accept { "accept" } else { "reject" }.to_string()),
+            ),
+        ])
+    }
+}
+
+struct CancelTransferRequest {
+    domain: String,
+}
+
+impl OpsCommand for CancelTransferRequest {
+    type Response = OrderResponse;
+
+    fn protocol(&self) -> &str {
+        "XCP"
+    }
+
+    fn object(&self) -> &str {
+        "TRANSFER"
+    }
+
+    fn action(&self) -> &str {
+        "CANCEL"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
+    fn attributes(&self) -> OpsValue {
+        OpsValue::Assoc(vec![(
+            "domain".to_string(),
+            OpsValue::Scalar(self.domain.clone()),
+        )])
+    }
+}
+
+impl OpenSrsClient {
+    /// Initiate an inbound transfer of a domain, using the auth code
+    /// obtained from the losing registrar.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn transfer_domain(
+        &self,
+        domain: &str,
+        auth_code: &str,
+        nameservers: &[String],
+    ) -> Result<OrderReceipt> {
+        let request = TransferDomainRequest {
+            domain: domain.to_string(),
+            auth_code: auth_code.to_string(),
+            nameservers: nameservers.to_vec(),
+        };
+
+        let response = self.send_request(&request)?;
+        Ok(response.receipt)
+    }
+
+    /// Query the status of a previously-initiated inbound transfer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn get_transfer_status(&self, domain: &str) -> Result<TransferStatus> {
+        let request = GetTransferStatusRequest {
+            domain: domain.to_string(),
+        };
+
+        let response = self.send_request(&request)?;
+        Ok(response.status)
+    }
+
+    /// Accept or reject a pending inbound transfer that requires manual
+    /// approval.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn process_transfer(&self, domain: &str, accept: bool) -> Result<OrderReceipt> {
+        let request = ProcessTransferRequest {
+            domain: domain.to_string(),
+            accept,
+        };
+
+        let response = self.send_request(&request)?;
+        Ok(response.receipt)
+    }
+
+    /// Cancel a pending outbound or inbound transfer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or returns an error response.
+    pub fn cancel_transfer(&self, domain: &str) -> Result<OrderReceipt> {
+        let request = CancelTransferRequest {
+            domain: domain.to_string(),
+        };
+
+        let response = self.send_request(&request)?;
+        Ok(response.receipt)
+    }
+}
@@ -7,6 +7,11 @@ pub enum OpenSrsError {
     #[error("HTTP request failed: {0}")]
     HttpError(#[from] ureq::Error),
 
+    /// HTTP request failed (async transport)
+    #[cfg(feature = "async")]
+    #[error("HTTP request failed: {0}")]
+    AsyncHttpError(#[from] reqwest::Error),
+
     /// XML serialization failed
     #[error("XML serialization failed: {0}")]
     XmlSerialize(#[from] quick_xml::Error),
@@ -15,13 +20,31 @@ pub enum OpenSrsError {
     #[error("XML deserialization failed: {0}")]
     XmlDeserialize(String),
 
-    /// API returned an error response
-    #[error("API returned error: {code} - {message}")]
-    ApiError { code: String, message: String },
+    /// Authentication with OpenSRS failed (bad credentials, disallowed IP, ...)
+    #[error("Authentication failed (code {code}): {message}")]
+    AuthenticationFailed { code: String, message: String },
+
+    /// Request was throttled by OpenSRS's per-account rate limit
+    #[error("Rate limited by OpenSRS (code {code}): {message}")]
+    RateLimited { code: String, message: String },
+
+    /// The domain is unavailable for the attempted operation (already
+    /// registered, not eligible for transfer, etc.)
+    #[error("Domain unavailable (code {code}): {message}")]
+    DomainUnavailable { code: String, message: String },
 
-    /// Authentication failed
-    #[error("Authentication failed: {0}")]
-    AuthError(String),
+    /// One or more request parameters were rejected by OpenSRS
+    #[error("Invalid parameter (code {code}): {message}")]
+    InvalidParameter { code: String, message: String },
+
+    /// The requested object (domain, contact, nameserver, ...) does not exist
+    #[error("Object not found (code {code}): {message}")]
+    ObjectNotFound { code: String, message: String },
+
+    /// Any other non-success response from the registry, carrying the raw
+    /// code and message for callers that need to fall back to string matching
+    #[error("Registry error (code {code}): {message}")]
+    Registry { code: String, message: String },
 
     /// Invalid configuration
     #[error("Invalid configuration: {0}")]
@@ -30,7 +53,72 @@ pub enum OpenSrsError {
     /// Invalid date format
     #[error("Invalid date format: {0}")]
     DateFormatError(String),
+
+    /// Response cache operation failed
+    #[error("Cache error: {0}")]
+    CacheError(String),
+
+    /// The client's [`RetryPolicy`](super::retry::RetryPolicy) gave up after
+    /// repeated transient failures; `source` is the last underlying error.
+    #[error("gave up after {attempts} attempts: {source}")]
+    RetryExhausted {
+        attempts: u32,
+        source: Box<OpenSrsError>,
+    },
 }
 
 /// Result type alias for OpenSRS operations
 pub type Result<T> = std::result::Result<T, OpenSrsError>;
+
+/// Classify a non-success OpenSRS response into a specific [`OpenSrsError`]
+/// variant based on its `response_code`, falling back to [`OpenSrsError::Registry`]
+/// for codes that don't map onto a more specific condition.
+///
+/// Codes are OpenSRS's own numeric response codes, not HTTP status codes.
+pub(crate) fn classify_response_error(code: &str, message: &str) -> OpenSrsError {
+    let code = code.to_string();
+    let message = message.to_string();
+
+    match code.as_str() {
+        "400" | "401" | "415" => OpenSrsError::AuthenticationFailed { code, message },
+        "465" => OpenSrsError::RateLimited { code, message },
+        "408" => OpenSrsError::InvalidParameter { code, message },
+        "436" | "437" => OpenSrsError::DomainUnavailable { code, message },
+        "485" => OpenSrsError::ObjectNotFound { code, message },
+        _ => OpenSrsError::Registry { code, message },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_response_error_matches_documented_code_boundaries() {
+        let cases = [
+            ("400", "AuthenticationFailed"),
+            ("401", "AuthenticationFailed"),
+            ("415", "AuthenticationFailed"),
+            ("465", "RateLimited"),
+            ("408", "InvalidParameter"),
+            ("436", "DomainUnavailable"),
+            ("437", "DomainUnavailable"),
+            ("485", "ObjectNotFound"),
+            ("999", "Registry"),
+        ];
+
+        for (code, expected_variant) in cases {
+            let error = classify_response_error(code, "message");
+            let variant = match error {
+                OpenSrsError::AuthenticationFailed { .. } => "AuthenticationFailed",
+                OpenSrsError::RateLimited { .. } => "RateLimited",
+                OpenSrsError::InvalidParameter { .. } => "InvalidParameter",
+                OpenSrsError::DomainUnavailable { .. } => "DomainUnavailable",
+                OpenSrsError::ObjectNotFound { .. } => "ObjectNotFound",
+                OpenSrsError::Registry { .. } => "Registry",
+                _ => "other",
+            };
+            assert_eq!(variant, expected_variant, "code {code} classified unexpectedly");
+        }
+    }
+}
@@ -1,11 +1,34 @@
+#[cfg(feature = "async")]
+mod async_client;
 mod auth;
+mod cache;
 mod client;
+mod command;
+mod dns;
 mod domain;
 mod error;
+mod raw;
+mod retry;
+mod scheduler;
+mod throttle;
 mod types;
+mod value;
 mod xml;
 
 // Public exports
+#[cfg(feature = "async")]
+pub use async_client::AsyncOpenSrsClient;
+pub use cache::CacheConfig;
 pub use client::OpenSrsClient;
+pub use dns::DnsRecord;
+pub use domain::{
+    ContactInfo, ContactInfoBuilder, ContactSet, ContactSetBuilder, DomainAvailability,
+    OrderReceipt, RegistrationOptions, RegistrationOptionsBuilder, TransferStatus,
+};
 pub use error::{OpenSrsError, Result};
+pub use raw::ParsedResponse;
+pub use retry::{RetryOn, RetryPolicy};
+pub use scheduler::{RenewalConfig, RenewalOutcome, RenewalSchedulerHandle};
+pub use throttle::ThrottleConfig;
 pub use types::{ClientConfig, Environment, ExpiringDomain};
+pub use value::OpsValue;
@@ -0,0 +1,210 @@
+//! Generic escape hatch for OpenSRS actions the typed API doesn't cover yet.
+//!
+//! [`OpenSrsClient::send_command`] reuses the same signing/codec plumbing as
+//! every typed command, but accepts a free-form `object`/`action`/attribute
+//! payload and hands back the full response value tree instead of a
+//! purpose-built struct, for actions nobody's gotten around to wrapping.
+
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use super::client::OpenSrsClient;
+use super::command::{OpsCommand, OpsResponse};
+use super::error::{OpenSrsError, Result};
+use super::value::{self, OpsValue};
+
+/// The parsed result of a [`OpenSrsClient::send_command`] call: the usual
+/// success/code/text triple, plus the full `attributes` value tree so
+/// callers can navigate whatever shape the action actually returned.
+pub struct ParsedResponse {
+    is_success: bool,
+    response_code: String,
+    response_text: String,
+    pub attributes: OpsValue,
+}
+
+impl ParsedResponse {
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
+
+    pub fn response_code(&self) -> &str {
+        &self.response_code
+    }
+
+    pub fn response_text(&self) -> &str {
+        &self.response_text
+    }
+}
+
+impl OpsResponse for ParsedResponse {
+    fn parse(xml: &str) -> Result<Self> {
+        value::parse_document(xml)?.try_into()
+    }
+
+    fn is_success(&self) -> bool {
+        self.is_success
+    }
+
+    fn response_code(&self) -> &str {
+        &self.response_code
+    }
+
+    fn response_text(&self) -> &str {
+        &self.response_text
+    }
+}
+
+impl TryFrom<OpsValue> for ParsedResponse {
+    type Error = OpenSrsError;
+
+    fn try_from(value: OpsValue) -> Result<Self> {
+        let is_success = value
+            .get("is_success")
+            .and_then(OpsValue::as_scalar)
+            .is_some_and(|s| s == "1" || s.eq_ignore_ascii_case("true"));
+        let response_code = value
+            .get("response_code")
+            .and_then(OpsValue::as_scalar)
+            .unwrap_or_default()
+            .to_string();
+        let response_text = value
+            .get("response_text")
+            .and_then(OpsValue::as_scalar)
+            .unwrap_or_default()
+            .to_string();
+        let attributes = value
+            .get("attributes")
+            .cloned()
+            .unwrap_or_else(|| OpsValue::Assoc(Vec::new()));
+
+        Ok(Self {
+            is_success,
+            response_code,
+            response_text,
+            attributes,
+        })
+    }
+}
+
+struct RawCommand {
+    protocol: String,
+    object: String,
+    action: String,
+    attributes: OpsValue,
+}
+
+impl OpsCommand for RawCommand {
+    type Response = ParsedResponse;
+
+    fn protocol(&self) -> &str {
+        &self.protocol
+    }
+
+    fn object(&self) -> &str {
+        &self.object
+    }
+
+    fn action(&self) -> &str {
+        &self.action
+    }
+
+    fn attributes(&self) -> OpsValue {
+        self.attributes.clone()
+    }
+
+    // The action is arbitrary, so there's no way to know it's safe to
+    // double-submit; callers that know otherwise can opt in via
+    // `retry_on.mutating_calls` same as any other mutating command.
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+}
+
+/// Convert a [`serde_json::Value`] into the `dt_assoc`/`dt_array` shape
+/// OpenSRS's wire format expects: objects and arrays recurse, everything
+/// else is stringified to a scalar.
+fn json_to_ops_value(value: &Value) -> OpsValue {
+    match value {
+        Value::Null => OpsValue::Scalar(String::new()),
+        Value::Bool(b) => OpsValue::Scalar(if *b { "1" } else { "0" }.to_string()),
+        Value::Number(n) => OpsValue::Scalar(n.to_string()),
+        Value::String(s) => OpsValue::Scalar(s.clone()),
+        Value::Array(items) => OpsValue::Array(items.iter().map(json_to_ops_value).collect()),
+        Value::Object(map) => {
+            OpsValue::Assoc(map.iter().map(|(k, v)| (k.clone(), json_to_ops_value(v))).collect())
+        }
+    }
+}
+
+impl OpenSrsClient {
+    /// Send an arbitrary OpenSRS `object`/`action` command with the given
+    /// attributes, for actions the typed API (`domain`, `dns`, ...) doesn't
+    /// cover yet. `attributes` accepts any JSON-shaped value, which is
+    /// translated into OpenSRS's `dt_assoc`/`dt_array` wire format.
+    pub fn send_command(
+        &self,
+        object: &str,
+        action: &str,
+        attributes: BTreeMap<String, Value>,
+    ) -> Result<ParsedResponse> {
+        let attributes = OpsValue::Assoc(
+            attributes
+                .into_iter()
+                .map(|(key, value)| (key, json_to_ops_value(&value)))
+                .collect(),
+        );
+
+        let request = RawCommand {
+            protocol: "XCP".to_string(),
+            object: object.to_string(),
+            action: action.to_string(),
+            attributes,
+        };
+
+        self.send_request(&request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_scalars_stringify() {
+        assert_eq!(json_to_ops_value(&Value::Null), OpsValue::Scalar(String::new()));
+        assert_eq!(json_to_ops_value(&json!(true)), OpsValue::Scalar("1".to_string()));
+        assert_eq!(json_to_ops_value(&json!(false)), OpsValue::Scalar("0".to_string()));
+        assert_eq!(json_to_ops_value(&json!(42)), OpsValue::Scalar("42".to_string()));
+        assert_eq!(
+            json_to_ops_value(&json!("hello")),
+            OpsValue::Scalar("hello".to_string())
+        );
+    }
+
+    #[test]
+    fn test_json_array_recurses() {
+        let value = json_to_ops_value(&json!(["a", "b"]));
+        assert_eq!(
+            value,
+            OpsValue::Array(vec![
+                OpsValue::Scalar("a".to_string()),
+                OpsValue::Scalar("b".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_json_object_recurses() {
+        let value = json_to_ops_value(&json!({"nested": {"flag": true}}));
+        assert_eq!(
+            value,
+            OpsValue::Assoc(vec![(
+                "nested".to_string(),
+                OpsValue::Assoc(vec![("flag".to_string(), OpsValue::Scalar("1".to_string()))]),
+            )])
+        );
+    }
+}
@@ -0,0 +1,117 @@
+//! Retry/backoff policy for transient OpenSRS failures
+//!
+//! This is deliberately split from [`Throttle`](super::throttle::Throttle):
+//! the throttle bounds how many requests are in flight and how fast they're
+//! sent, while [`RetryPolicy`] decides whether a failed request gets sent
+//! again at all, and under what conditions.
+
+use std::time::Duration;
+
+/// Which categories of failure a [`RetryPolicy`] will retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryOn {
+    /// Retry a rate-limit response from OpenSRS (response code 465)
+    pub rate_limit: bool,
+    /// Retry a transport-level connection error or timeout
+    pub connection_error: bool,
+    /// Retry commands that are not idempotent (register, renew, transfer,
+    /// delete, ...). Leave this `false` unless the caller has its own
+    /// de-duplication, since retrying a provisioning call can double-submit
+    /// an order.
+    pub mutating_calls: bool,
+}
+
+impl RetryOn {
+    /// Retry rate limits and connection errors for idempotent (read-only)
+    /// commands only; never retry provisioning commands. This is the default.
+    pub const fn idempotent_only() -> Self {
+        Self {
+            rate_limit: true,
+            connection_error: true,
+            mutating_calls: false,
+        }
+    }
+
+    /// Retry rate limits, connection errors, and provisioning commands alike.
+    /// Only use this if the caller can safely handle a provisioning command
+    /// running more than once (e.g. by checking order status afterward).
+    pub const fn all() -> Self {
+        Self {
+            rate_limit: true,
+            connection_error: true,
+            mutating_calls: true,
+        }
+    }
+
+    /// Never retry anything automatically.
+    pub const fn never() -> Self {
+        Self {
+            rate_limit: false,
+            connection_error: false,
+            mutating_calls: false,
+        }
+    }
+}
+
+impl Default for RetryOn {
+    fn default() -> Self {
+        Self::idempotent_only()
+    }
+}
+
+/// Configuration for the client's retry/backoff behavior
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum retry attempts after a retryable failure
+    pub max_retries: u32,
+    /// Base delay for exponential backoff between retries
+    pub base_delay: Duration,
+    /// Upper bound on the backoff delay, regardless of attempt count
+    pub max_delay: Duration,
+    /// Which failures this policy retries, and for which commands
+    pub retry_on: RetryOn,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            retry_on: RetryOn::default(),
+        }
+    }
+}
+
+/// Exponential backoff with full jitter: `[0.5, 1.0] * min(max_delay, base * 2^attempt)`
+pub(crate) fn backoff_delay(attempt: u32, base_delay: Duration, max_delay: Duration) -> Duration {
+    let exp = base_delay.as_secs_f64() * 2f64.powi(attempt as i32);
+    let capped = exp.min(max_delay.as_secs_f64());
+    let jitter = 0.5 + rand::random::<f64>() * 0.5;
+    Duration::from_secs_f64(capped * jitter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_delay_stays_within_bounds() {
+        let base = Duration::from_millis(100);
+        let max = Duration::from_secs(2);
+
+        for attempt in 0..10 {
+            let delay = backoff_delay(attempt, base, max);
+            assert!(delay <= max);
+            assert!(delay >= base.mul_f64(0.5).min(max));
+        }
+    }
+
+    #[test]
+    fn test_idempotent_only_excludes_mutating_calls() {
+        let retry_on = RetryOn::idempotent_only();
+        assert!(retry_on.rate_limit);
+        assert!(retry_on.connection_error);
+        assert!(!retry_on.mutating_calls);
+    }
+}
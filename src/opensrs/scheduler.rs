@@ -0,0 +1,200 @@
+//! Unattended auto-renewal scheduler
+//!
+//! [`OpenSrsClient::spawn_renewal_scheduler`] turns the crate from a
+//! request/response wrapper into something that can keep a portfolio of
+//! domains alive on its own: on a fixed interval it fetches domains expiring
+//! within a lead-time window via [`refresh_domains_by_expiredate`] (bypassing
+//! any response cache), renews the ones the caller's filter accepts, and
+//! reports each outcome back.
+//!
+//! [`refresh_domains_by_expiredate`]: super::client::OpenSrsClient::refresh_domains_by_expiredate
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use chrono::Local;
+
+use super::client::OpenSrsClient;
+use super::error::OpenSrsError;
+use super::types::ExpiringDomain;
+
+/// How often the scheduler checks for expiring domains, and which ones it
+/// renews.
+pub struct RenewalConfig {
+    /// How often to check for expiring domains
+    pub check_interval: Duration,
+    /// How far ahead of expiration to start renewing, e.g. 30 days
+    pub lead_time: chrono::Duration,
+    /// Only domains this predicate accepts are renewed, in addition to the
+    /// registry's own `f_auto_renew` flag
+    pub filter: Box<dyn Fn(&ExpiringDomain) -> bool + Send>,
+    /// Called once per domain with the outcome of its renewal attempt
+    pub on_result: Box<dyn Fn(RenewalOutcome) + Send>,
+}
+
+/// The outcome of a single domain's auto-renewal attempt
+#[derive(Debug)]
+pub enum RenewalOutcome {
+    /// The domain was renewed successfully
+    Renewed { domain: String, order_id: String },
+    /// The renewal attempt failed
+    Failed { domain: String, error: OpenSrsError },
+}
+
+/// A handle to a running renewal scheduler
+///
+/// Dropping the handle does not stop the scheduler; call [`cancel`](Self::cancel)
+/// to stop it, or [`join`](Self::join) to block until it stops on its own
+/// (it otherwise runs forever).
+pub struct RenewalSchedulerHandle {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl RenewalSchedulerHandle {
+    /// Signal the scheduler to stop after its current tick
+    pub fn cancel(&self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+
+    /// Block until the scheduler thread exits
+    pub fn join(&mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl OpenSrsClient {
+    /// Spawn a background thread that periodically renews expiring,
+    /// auto-renew-flagged domains according to `config`.
+    ///
+    /// This takes ownership of the client, since it's now driven entirely by
+    /// the background thread; the returned handle only controls the thread's
+    /// lifetime.
+    pub fn spawn_renewal_scheduler(self, config: RenewalConfig) -> RenewalSchedulerHandle {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+
+        let thread = thread::spawn(move || {
+            while !thread_stop.load(Ordering::Relaxed) {
+                self.run_renewal_tick(&config);
+                sleep_cancelable(config.check_interval, &thread_stop);
+            }
+        });
+
+        RenewalSchedulerHandle {
+            stop,
+            thread: Some(thread),
+        }
+    }
+
+    fn run_renewal_tick(&self, config: &RenewalConfig) {
+        let today = Local::now().date_naive();
+        let Some(horizon) = today.checked_add_signed(config.lead_time) else {
+            return;
+        };
+
+        let Ok(expiring) = self.refresh_domains_by_expiredate(today, horizon) else {
+            return;
+        };
+
+        for domain in expiring {
+            if !should_renew(&domain, &config.filter) {
+                continue;
+            }
+
+            let outcome = match parse_expiration_year(&domain.expiredate) {
+                Some(current_expiration_year) => {
+                    match self.renew_domain(&domain.name, 1, current_expiration_year) {
+                        Ok(receipt) => RenewalOutcome::Renewed {
+                            domain: domain.name.clone(),
+                            order_id: receipt.order_id,
+                        },
+                        Err(error) => RenewalOutcome::Failed {
+                            domain: domain.name.clone(),
+                            error,
+                        },
+                    }
+                }
+                None => RenewalOutcome::Failed {
+                    domain: domain.name.clone(),
+                    error: OpenSrsError::DateFormatError(domain.expiredate.clone()),
+                },
+            };
+
+            (config.on_result)(outcome);
+        }
+    }
+}
+
+/// Whether a domain should be auto-renewed this tick: the registry's own
+/// `f_auto_renew` flag must be set, and the caller's filter must also accept it.
+fn should_renew(domain: &ExpiringDomain, filter: &(dyn Fn(&ExpiringDomain) -> bool + Send)) -> bool {
+    domain.f_auto_renew == "1" && filter(domain)
+}
+
+/// Parse the expiration year out of an `expiredate` like `"2026-01-01"`.
+///
+/// Returns `None` if the leading year component is missing or non-numeric,
+/// so the caller can report a [`RenewalOutcome::Failed`] instead of renewing
+/// with a garbage year.
+fn parse_expiration_year(expiredate: &str) -> Option<u32> {
+    expiredate.split('-').next()?.parse().ok()
+}
+
+/// Sleep for `duration`, waking early in short increments to notice
+/// cancellation without waiting out the full interval.
+fn sleep_cancelable(duration: Duration, stop: &AtomicBool) {
+    const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+    let mut remaining = duration;
+    while remaining > Duration::ZERO {
+        if stop.load(Ordering::Relaxed) {
+            return;
+        }
+        let step = remaining.min(POLL_INTERVAL);
+        thread::sleep(step);
+        remaining -= step;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_domain(f_auto_renew: &str) -> ExpiringDomain {
+        ExpiringDomain {
+            name: "example.com".to_string(),
+            expiredate: "2026-03-15".to_string(),
+            f_auto_renew: f_auto_renew.to_string(),
+            f_let_expire: "0".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_expiration_year_extracts_leading_year() {
+        assert_eq!(parse_expiration_year("2026-03-15"), Some(2026));
+    }
+
+    #[test]
+    fn test_parse_expiration_year_rejects_malformed_date() {
+        assert_eq!(parse_expiration_year("not-a-date"), None);
+        assert_eq!(parse_expiration_year(""), None);
+    }
+
+    #[test]
+    fn test_should_renew_requires_auto_renew_flag() {
+        let domain = sample_domain("0");
+        assert!(!should_renew(&domain, &|_| true));
+    }
+
+    #[test]
+    fn test_should_renew_requires_filter_to_accept() {
+        let domain = sample_domain("1");
+        assert!(!should_renew(&domain, &|_| false));
+        assert!(should_renew(&domain, &|_| true));
+    }
+}
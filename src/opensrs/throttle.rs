@@ -0,0 +1,221 @@
+//! Client-side concurrency and throughput limiting
+//!
+//! OpenSRS enforces per-account request throttling; without client-side
+//! coordination, the pagination loop in [`domain`](super::domain) can fire
+//! enough requests in a burst to trip it. [`Throttle`] bounds the client to a
+//! maximum number of in-flight requests (a counting semaphore) and a
+//! sustained request rate (a token bucket), shared across every command the
+//! client sends.
+
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// Configuration for the client's concurrency/throughput governor
+///
+/// This only bounds how many requests are in flight and how fast they're
+/// sent; whether a failed request is retried at all is a separate concern,
+/// see [`RetryPolicy`](super::retry::RetryPolicy).
+#[derive(Debug, Clone)]
+pub struct ThrottleConfig {
+    /// Maximum number of requests in flight at once
+    pub max_in_flight: usize,
+    /// Maximum sustained requests per second
+    pub requests_per_second: f64,
+    /// Token bucket burst capacity
+    pub burst: u32,
+}
+
+impl Default for ThrottleConfig {
+    fn default() -> Self {
+        Self {
+            max_in_flight: 4,
+            requests_per_second: 5.0,
+            burst: 5,
+        }
+    }
+}
+
+struct ThrottleState {
+    in_flight: usize,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl ThrottleState {
+    fn refill(&mut self, refill_rate: f64, burst: f64) {
+        if refill_rate <= 0.0 {
+            return;
+        }
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * refill_rate).min(burst);
+        self.last_refill = now;
+    }
+}
+
+/// Bounds in-flight requests and sustained throughput across the whole client
+pub(crate) struct Throttle {
+    max_in_flight: usize,
+    refill_rate: f64,
+    burst: f64,
+    state: Mutex<ThrottleState>,
+    cond: Condvar,
+}
+
+impl Throttle {
+    pub(crate) fn new(config: &ThrottleConfig) -> Self {
+        Self {
+            max_in_flight: config.max_in_flight.max(1),
+            refill_rate: config.requests_per_second.max(0.0),
+            burst: f64::from(config.burst).max(1.0),
+            state: Mutex::new(ThrottleState {
+                in_flight: 0,
+                tokens: f64::from(config.burst).max(1.0),
+                last_refill: Instant::now(),
+            }),
+            cond: Condvar::new(),
+        }
+    }
+
+    /// Block until a concurrency slot and a rate-limit token are both available
+    pub(crate) fn acquire(&self) -> ThrottlePermit<'_> {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            state.refill(self.refill_rate, self.burst);
+
+            if state.in_flight < self.max_in_flight && state.tokens >= 1.0 {
+                state.in_flight += 1;
+                state.tokens -= 1.0;
+                break;
+            }
+
+            let wait = if self.refill_rate > 0.0 {
+                Duration::from_secs_f64(((1.0 - state.tokens).max(0.0) / self.refill_rate).max(0.001))
+            } else {
+                Duration::from_millis(50)
+            };
+            let (guard, _timeout) = self.cond.wait_timeout(state, wait).unwrap();
+            state = guard;
+        }
+
+        ThrottlePermit { throttle: self }
+    }
+
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.in_flight = state.in_flight.saturating_sub(1);
+        drop(state);
+        self.cond.notify_one();
+    }
+}
+
+/// RAII guard that releases the throttle's concurrency slot on drop
+pub(crate) struct ThrottlePermit<'a> {
+    throttle: &'a Throttle,
+}
+
+impl Drop for ThrottlePermit<'_> {
+    fn drop(&mut self) {
+        self.throttle.release();
+    }
+}
+
+/// Async-native counterpart to [`Throttle`], used by
+/// [`AsyncOpenSrsClient`](super::async_client::AsyncOpenSrsClient).
+///
+/// The bookkeeping (`in_flight`/token-bucket state) is still behind a plain
+/// [`Mutex`], but the lock is only ever held for the brief check-and-update —
+/// never across a wait. Waiting for a slot to free up uses
+/// [`tokio::time::sleep`] instead of [`std::thread::sleep`]/[`Condvar::wait_timeout`],
+/// so a send yields its task back to the runtime instead of blocking a
+/// worker thread for the duration of the wait.
+#[cfg(feature = "async")]
+pub(crate) struct AsyncThrottle {
+    max_in_flight: usize,
+    refill_rate: f64,
+    burst: f64,
+    state: Mutex<ThrottleState>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncThrottle {
+    pub(crate) fn new(config: &ThrottleConfig) -> Self {
+        Self {
+            max_in_flight: config.max_in_flight.max(1),
+            refill_rate: config.requests_per_second.max(0.0),
+            burst: f64::from(config.burst).max(1.0),
+            state: Mutex::new(ThrottleState {
+                in_flight: 0,
+                tokens: f64::from(config.burst).max(1.0),
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Wait until a concurrency slot and a rate-limit token are both
+    /// available, without blocking the calling task's worker thread.
+    pub(crate) async fn acquire(&self) -> AsyncThrottlePermit<'_> {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                state.refill(self.refill_rate, self.burst);
+
+                if state.in_flight < self.max_in_flight && state.tokens >= 1.0 {
+                    state.in_flight += 1;
+                    state.tokens -= 1.0;
+                    None
+                } else if self.refill_rate > 0.0 {
+                    Some(Duration::from_secs_f64(
+                        ((1.0 - state.tokens).max(0.0) / self.refill_rate).max(0.001),
+                    ))
+                } else {
+                    Some(Duration::from_millis(50))
+                }
+            };
+
+            match wait {
+                None => break,
+                Some(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+
+        AsyncThrottlePermit { throttle: self }
+    }
+
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.in_flight = state.in_flight.saturating_sub(1);
+    }
+}
+
+/// RAII guard that releases the [`AsyncThrottle`]'s concurrency slot on drop
+#[cfg(feature = "async")]
+pub(crate) struct AsyncThrottlePermit<'a> {
+    throttle: &'a AsyncThrottle,
+}
+
+#[cfg(feature = "async")]
+impl Drop for AsyncThrottlePermit<'_> {
+    fn drop(&mut self) {
+        self.throttle.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acquire_respects_max_in_flight() {
+        let throttle = Throttle::new(&ThrottleConfig {
+            max_in_flight: 1,
+            requests_per_second: 0.0,
+            burst: 10,
+        });
+
+        let first = throttle.acquire();
+        assert_eq!(throttle.state.lock().unwrap().in_flight, 1);
+        drop(first);
+        assert_eq!(throttle.state.lock().unwrap().in_flight, 0);
+    }
+}
@@ -1,5 +1,12 @@
 use serde::{Deserialize, Serialize};
 
+use super::cache::CacheConfig;
+use super::command::{OpsCommand, OpsResponse, Paginated};
+use super::error::{OpenSrsError, Result};
+use super::retry::RetryPolicy;
+use super::throttle::ThrottleConfig;
+use super::value::{self, OpsValue};
+
 /// OpenSRS API environment (test or production)
 #[derive(Debug, Clone)]
 pub enum Environment {
@@ -28,29 +35,16 @@ pub struct ClientConfig {
     pub credential: String,
     /// Environment to use (test or production)
     pub environment: Environment,
-}
-
-/// XCP protocol envelope structure
-#[derive(Debug, Serialize, Deserialize)]
-pub struct OpsEnvelope<T> {
-    pub header: OpsHeader,
-    pub body: OpsBody<T>,
-}
-
-/// XCP protocol header
-#[derive(Debug, Serialize, Deserialize)]
-pub struct OpsHeader {
-    pub version: String,
-}
-
-/// XCP protocol body
-#[derive(Debug, Serialize, Deserialize)]
-pub struct OpsBody<T> {
-    pub data_block: T,
+    /// SQLite-backed response cache for list-style lookups, disabled if `None`
+    pub cache: Option<CacheConfig>,
+    /// Client-side concurrency and throughput governor
+    pub throttle: ThrottleConfig,
+    /// Retry/backoff policy for transient failures
+    pub retry: RetryPolicy,
 }
 
 /// Request to get domains by expiration date
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
 pub struct GetDomainsByExpireDateRequest {
     pub protocol: String,
     pub object: String,
@@ -59,18 +53,16 @@ pub struct GetDomainsByExpireDateRequest {
 }
 
 /// Attributes for get_domains_by_expiredate request
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
 pub struct GetDomainsByExpireDateAttrs {
     pub exp_from: String,
     pub exp_to: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub page: Option<u32>,
 }
 
 /// Response from get_domains_by_expiredate
-#[derive(Debug, Deserialize)]
+#[derive(Debug)]
 pub struct GetDomainsByExpireDateResponse {
     pub is_success: bool,
     pub response_code: String,
@@ -79,21 +71,160 @@ pub struct GetDomainsByExpireDateResponse {
 }
 
 /// Response attributes for get_domains_by_expiredate
-#[derive(Debug, Deserialize)]
+#[derive(Debug)]
 pub struct GetDomainsByExpireDateResponseAttrs {
     pub page: u32,
     pub total: u32,
     /// 0 = all results returned, 1 = more pages available
     pub remainder: u8,
-    #[serde(default)]
     pub exp_domains: Vec<ExpiringDomain>,
 }
 
 /// Information about an expiring domain
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ExpiringDomain {
     pub name: String,
     pub expiredate: String,
     pub f_auto_renew: String,
     pub f_let_expire: String,
 }
+
+impl OpsCommand for GetDomainsByExpireDateRequest {
+    type Response = GetDomainsByExpireDateResponse;
+
+    fn protocol(&self) -> &str {
+        &self.protocol
+    }
+
+    fn object(&self) -> &str {
+        &self.object
+    }
+
+    fn action(&self) -> &str {
+        &self.action
+    }
+
+    fn attributes(&self) -> OpsValue {
+        let mut pairs = vec![
+            (
+                "exp_from".to_string(),
+                OpsValue::Scalar(self.attributes.exp_from.clone()),
+            ),
+            (
+                "exp_to".to_string(),
+                OpsValue::Scalar(self.attributes.exp_to.clone()),
+            ),
+        ];
+
+        if let Some(limit) = self.attributes.limit {
+            pairs.push(("limit".to_string(), OpsValue::Scalar(limit.to_string())));
+        }
+
+        if let Some(page) = self.attributes.page {
+            pairs.push(("page".to_string(), OpsValue::Scalar(page.to_string())));
+        }
+
+        OpsValue::Assoc(pairs)
+    }
+}
+
+impl OpsResponse for GetDomainsByExpireDateResponse {
+    fn parse(xml_body: &str) -> Result<Self> {
+        value::parse_document(xml_body)?.try_into()
+    }
+
+    fn is_success(&self) -> bool {
+        self.is_success
+    }
+
+    fn response_code(&self) -> &str {
+        &self.response_code
+    }
+
+    fn response_text(&self) -> &str {
+        &self.response_text
+    }
+}
+
+impl Paginated for GetDomainsByExpireDateResponse {
+    type Item = ExpiringDomain;
+
+    fn remainder(&self) -> u8 {
+        self.attributes.remainder
+    }
+
+    fn into_items(self) -> Vec<ExpiringDomain> {
+        self.attributes.exp_domains
+    }
+}
+
+impl TryFrom<OpsValue> for GetDomainsByExpireDateResponse {
+    type Error = OpenSrsError;
+
+    fn try_from(value: OpsValue) -> Result<Self> {
+        let is_success = value
+            .get("is_success")
+            .and_then(OpsValue::as_scalar)
+            .is_some_and(|s| s == "1" || s.eq_ignore_ascii_case("true"));
+        let response_code = value
+            .get("response_code")
+            .and_then(OpsValue::as_scalar)
+            .unwrap_or_default()
+            .to_string();
+        let response_text = value
+            .get("response_text")
+            .and_then(OpsValue::as_scalar)
+            .unwrap_or_default()
+            .to_string();
+
+        let attrs = value.get("attributes");
+        let parse_u32 = |key: &str| -> u32 {
+            attrs
+                .and_then(|a| a.get(key))
+                .and_then(OpsValue::as_scalar)
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0)
+        };
+
+        let exp_domains = attrs
+            .and_then(|a| a.get("exp_domains"))
+            .map(OpsValue::array_items)
+            .unwrap_or_default()
+            .into_iter()
+            .map(ExpiringDomain::try_from)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            is_success,
+            response_code,
+            response_text,
+            attributes: GetDomainsByExpireDateResponseAttrs {
+                page: parse_u32("page"),
+                total: parse_u32("total"),
+                remainder: parse_u32("remainder") as u8,
+                exp_domains,
+            },
+        })
+    }
+}
+
+impl TryFrom<OpsValue> for ExpiringDomain {
+    type Error = OpenSrsError;
+
+    fn try_from(value: OpsValue) -> Result<Self> {
+        let field = |key: &str| {
+            value
+                .get(key)
+                .and_then(OpsValue::as_scalar)
+                .unwrap_or_default()
+                .to_string()
+        };
+
+        Ok(Self {
+            name: field("name"),
+            expiredate: field("expiredate"),
+            f_auto_renew: field("f_auto_renew"),
+            f_let_expire: field("f_let_expire"),
+        })
+    }
+}
@@ -0,0 +1,229 @@
+use super::error::{OpenSrsError, Result};
+
+/// A node in OpenSRS's XCP `dt_assoc`/`dt_array` value tree.
+///
+/// OpenSRS's OPS protocol nests three shapes inside `<data_block>`: scalar
+/// text, `<dt_assoc>` (an ordered key/value map), and `<dt_array>` (an
+/// ordered list, keyed `0`, `1`, ... by convention). Modelling them as one
+/// recursive enum lets both serialization and parsing walk arbitrarily deep
+/// structures instead of special-casing a single known shape.
+///
+/// Exposed publicly so [`send_command`](super::client::OpenSrsClient::send_command)
+/// can hand back a navigable result for actions the typed API doesn't cover.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpsValue {
+    Scalar(String),
+    Assoc(Vec<(String, OpsValue)>),
+    Array(Vec<OpsValue>),
+}
+
+impl OpsValue {
+    /// Look up a key in an `Assoc` node. Returns `None` for any other shape.
+    pub fn get(&self, key: &str) -> Option<&OpsValue> {
+        match self {
+            OpsValue::Assoc(pairs) => pairs.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Borrow the text of a `Scalar` node. Returns `None` for any other shape.
+    pub fn as_scalar(&self) -> Option<&str> {
+        match self {
+            OpsValue::Scalar(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Clone the elements of an `Array` node. Any other shape yields an empty vec.
+    pub fn array_items(&self) -> Vec<OpsValue> {
+        match self {
+            OpsValue::Array(items) => items.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Write this value's XML representation (without an enclosing `<item>`).
+    pub(crate) fn write(&self, xml: &mut String) {
+        match self {
+            OpsValue::Scalar(s) => xml.push_str(&escape_text(s)),
+            OpsValue::Assoc(pairs) => {
+                xml.push_str("<dt_assoc>");
+                for (key, value) in pairs {
+                    write_item(xml, key, value);
+                }
+                xml.push_str("</dt_assoc>");
+            }
+            OpsValue::Array(items) => {
+                xml.push_str("<dt_array>");
+                for (index, value) in items.iter().enumerate() {
+                    write_item(xml, &index.to_string(), value);
+                }
+                xml.push_str("</dt_array>");
+            }
+        }
+    }
+}
+
+/// Write `<item key="...">...</item>` for one entry of a container.
+fn write_item(xml: &mut String, key: &str, value: &OpsValue) {
+    xml.push_str("<item key=\"");
+    xml.push_str(&escape_attr(key));
+    xml.push_str("\">");
+    value.write(xml);
+    xml.push_str("</item>");
+}
+
+fn escape_text(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+fn escape_attr(s: &str) -> String {
+    escape_text(s).replace('"', "&quot;").replace('\'', "&apos;")
+}
+
+/// Parse the `<data_block>` payload of an OPS envelope into an [`OpsValue`] tree.
+///
+/// OpenSRS's XML isn't namespaced or schema'd beyond `dt_assoc`/`dt_array`/
+/// `item`, so this tracks a stack of open containers: `<dt_assoc>`/
+/// `<dt_array>` push a new container (tagged with the key its parent `<item>`
+/// gave it, if any), `<item key="...">` records the pending key for the next
+/// scalar or container, and the matching close pops a container and attaches
+/// it to its parent.
+pub(crate) fn parse_document(xml: &str) -> Result<OpsValue> {
+    use quick_xml::Reader;
+    use quick_xml::events::Event;
+
+    enum Container {
+        Assoc(Vec<(String, OpsValue)>),
+        Array(Vec<OpsValue>),
+    }
+
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut stack: Vec<(Option<String>, Container)> = Vec::new();
+    let mut pending_key: Option<String> = None;
+    let mut root: Option<OpsValue> = None;
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                match name.as_str() {
+                    "dt_assoc" => stack.push((pending_key.take(), Container::Assoc(Vec::new()))),
+                    "dt_array" => stack.push((pending_key.take(), Container::Array(Vec::new()))),
+                    "item" => {
+                        for attr in e.attributes().flatten() {
+                            if attr.key.as_ref() == b"key" {
+                                pending_key =
+                                    Some(String::from_utf8_lossy(&attr.value).to_string());
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Ok(Event::Text(e)) => {
+                let text = e.unescape().unwrap_or_default().trim().to_string();
+                if text.is_empty() {
+                    continue;
+                }
+                if let Some(key) = pending_key.take() {
+                    if let Some((_, container)) = stack.last_mut() {
+                        match container {
+                            Container::Assoc(pairs) => pairs.push((key, OpsValue::Scalar(text))),
+                            Container::Array(items) => items.push(OpsValue::Scalar(text)),
+                        }
+                    }
+                }
+            }
+            Ok(Event::End(e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if name == "dt_assoc" || name == "dt_array" {
+                    let Some((key, container)) = stack.pop() else {
+                        continue;
+                    };
+                    let value = match container {
+                        Container::Assoc(pairs) => OpsValue::Assoc(pairs),
+                        Container::Array(items) => OpsValue::Array(items),
+                    };
+
+                    match stack.last_mut() {
+                        Some((_, Container::Assoc(pairs))) => {
+                            if let Some(key) = key {
+                                pairs.push((key, value));
+                            }
+                        }
+                        Some((_, Container::Array(items))) => items.push(value),
+                        None => root = Some(value),
+                    }
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(OpenSrsError::XmlDeserialize(format!(
+                    "XML parse error: {}",
+                    e
+                )));
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    root.ok_or_else(|| OpenSrsError::XmlDeserialize("no dt_assoc/dt_array root found".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escapes_reserved_characters() {
+        let value = OpsValue::Scalar("Tom & Jerry <test> \"quote\"".to_string());
+        let mut xml = String::new();
+        value.write(&mut xml);
+        assert_eq!(xml, "Tom &amp; Jerry &lt;test&gt; \"quote\"");
+    }
+
+    #[test]
+    fn test_roundtrip_nested_assoc_and_array() {
+        let value = OpsValue::Assoc(vec![
+            ("protocol".to_string(), OpsValue::Scalar("XCP".to_string())),
+            (
+                "attributes".to_string(),
+                OpsValue::Assoc(vec![(
+                    "exp_domains".to_string(),
+                    OpsValue::Array(vec![
+                        OpsValue::Assoc(vec![(
+                            "name".to_string(),
+                            OpsValue::Scalar("example.com".to_string()),
+                        )]),
+                        OpsValue::Assoc(vec![(
+                            "name".to_string(),
+                            OpsValue::Scalar("example.net".to_string()),
+                        )]),
+                    ]),
+                )]),
+            ),
+        ]);
+
+        let mut xml = String::from("<data_block>");
+        value.write(&mut xml);
+        xml.push_str("</data_block>");
+
+        let parsed = parse_document(&xml).unwrap();
+        assert_eq!(parsed, value);
+
+        let exp_domains = parsed
+            .get("attributes")
+            .and_then(|a| a.get("exp_domains"))
+            .unwrap();
+        assert_eq!(exp_domains.array_items().len(), 2);
+        assert_eq!(
+            exp_domains.array_items()[0].get("name").and_then(OpsValue::as_scalar),
+            Some("example.com")
+        );
+    }
+}
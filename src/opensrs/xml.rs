@@ -1,11 +1,21 @@
-use super::error::{OpenSrsError, Result};
-use super::types::*;
+use super::command::{OpsCommand, OpsResponse};
+use super::error::Result;
+use super::value::OpsValue;
 
-/// Serialize request to OpenSRS XML format
+/// Serialize a command to OpenSRS XML format
 ///
-/// OpenSRS uses a non-standard XML structure with <dt_assoc> and <item key="..."> tags.
-/// We'll use manual XML construction for now instead of fighting with serde.
-pub fn serialize_request(request: &GetDomainsByExpireDateRequest) -> Result<String> {
+/// OpenSRS uses a non-standard XML structure built from `dt_assoc`/
+/// `dt_array`/`item` tags; the envelope boilerplate (DOCTYPE, header, body)
+/// is fixed, while the `data_block` body is a [`OpsValue`] tree assembled
+/// from the command's protocol/object/action triple and its attributes.
+pub fn serialize_request<C: OpsCommand>(command: &C) -> Result<String> {
+    let root = OpsValue::Assoc(vec![
+        ("protocol".to_string(), OpsValue::Scalar(command.protocol().to_string())),
+        ("object".to_string(), OpsValue::Scalar(command.object().to_string())),
+        ("action".to_string(), OpsValue::Scalar(command.action().to_string())),
+        ("attributes".to_string(), command.attributes()),
+    ]);
+
     let mut xml = String::from(
         r#"<?xml version='1.0' encoding='UTF-8' standalone='no' ?>
 <!DOCTYPE OPS_envelope SYSTEM 'ops.dtd'>
@@ -14,59 +24,11 @@ pub fn serialize_request(request: &GetDomainsByExpireDateRequest) -> Result<Stri
     <version>0.9</version>
   </header>
   <body>
-    <data_block>
-      <dt_assoc>
-        <item key="protocol">"#,
-    );
-    xml.push_str(&request.protocol);
-    xml.push_str(
-        r#"</item>
-        <item key="object">"#,
-    );
-    xml.push_str(&request.object);
-    xml.push_str(
-        r#"</item>
-        <item key="action">"#,
-    );
-    xml.push_str(&request.action);
-    xml.push_str(
-        r#"</item>
-        <item key="attributes">
-          <dt_assoc>
-            <item key="exp_from">"#,
-    );
-    xml.push_str(&request.attributes.exp_from);
-    xml.push_str(
-        r#"</item>
-            <item key="exp_to">"#,
+    <data_block>"#,
     );
-    xml.push_str(&request.attributes.exp_to);
-    xml.push_str("</item>");
-
-    if let Some(limit) = request.attributes.limit {
-        xml.push_str(
-            r#"
-            <item key="limit">"#,
-        );
-        xml.push_str(&limit.to_string());
-        xml.push_str("</item>");
-    }
-
-    if let Some(page) = request.attributes.page {
-        xml.push_str(
-            r#"
-            <item key="page">"#,
-        );
-        xml.push_str(&page.to_string());
-        xml.push_str("</item>");
-    }
-
+    root.write(&mut xml);
     xml.push_str(
-        r#"
-          </dt_assoc>
-        </item>
-      </dt_assoc>
-    </data_block>
+        r#"</data_block>
   </body>
 </OPS_envelope>
 "#,
@@ -75,136 +37,9 @@ pub fn serialize_request(request: &GetDomainsByExpireDateRequest) -> Result<Stri
     Ok(xml)
 }
 
-/// Deserialize OpenSRS XML response
-///
-/// OpenSRS uses a dt_assoc/item structure that requires custom parsing.
-pub fn deserialize_response(xml: &str) -> Result<GetDomainsByExpireDateResponse> {
-    use quick_xml::Reader;
-    use quick_xml::events::Event;
-
-    let mut reader = Reader::from_str(xml);
-    reader.config_mut().trim_text(true);
-
-    let mut is_success = false;
-    let mut response_code = String::new();
-    let mut response_text = String::new();
-    let mut page = 0u32;
-    let mut total = 0u32;
-    let mut remainder = 0u8;
-    let mut exp_domains = Vec::new();
-
-    let mut current_key = String::new();
-    let mut buf = Vec::new();
-
-    // Simple state machine to track where we are in the XML
-    let mut in_data_block = false;
-    #[allow(unused)]
-    let mut in_attributes = false;
-    let mut in_exp_domains = false;
-    let mut current_domain: Option<ExpiringDomain> = None;
-    #[allow(unused)]
-    let mut domain_field_key = String::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => {
-                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                if name == "data_block" {
-                    in_data_block = true;
-                } else if name == "item" {
-                    // Extract key attribute
-                    for attr in e.attributes() {
-                        if let Ok(attr) = attr {
-                            if attr.key.as_ref() == b"key" {
-                                current_key = String::from_utf8_lossy(&attr.value).to_string();
-                            }
-                        }
-                    }
-                }
-            }
-            Ok(Event::Text(e)) => {
-                if !in_data_block {
-                    continue;
-                }
-
-                let text = e.unescape().unwrap_or_default().trim().to_string();
-                if text.is_empty() {
-                    continue;
-                }
-
-                match current_key.as_str() {
-                    "is_success" => {
-                        is_success = text == "1" || text.to_lowercase() == "true";
-                    }
-                    "response_code" => response_code = text,
-                    "response_text" => response_text = text,
-                    "page" => page = text.parse().unwrap_or(0),
-                    "total" => total = text.parse().unwrap_or(0),
-                    "remainder" => remainder = text.parse().unwrap_or(0),
-                    "name" if in_exp_domains => {
-                        if let Some(ref mut domain) = current_domain {
-                            domain.name = text;
-                        } else {
-                            current_domain = Some(ExpiringDomain {
-                                name: text,
-                                expiredate: String::new(),
-                                f_auto_renew: String::new(),
-                                f_let_expire: String::new(),
-                            });
-                        }
-                    }
-                    "expiredate" if in_exp_domains => {
-                        if let Some(ref mut domain) = current_domain {
-                            domain.expiredate = text;
-                        }
-                    }
-                    "f_auto_renew" if in_exp_domains => {
-                        if let Some(ref mut domain) = current_domain {
-                            domain.f_auto_renew = text;
-                        }
-                    }
-                    "f_let_expire" if in_exp_domains => {
-                        if let Some(ref mut domain) = current_domain {
-                            domain.f_let_expire = text;
-                            // Domain complete, add to list
-                            exp_domains.push(domain.clone());
-                            current_domain = None;
-                        }
-                    }
-                    "attributes" => in_attributes = true,
-                    "exp_domains" => in_exp_domains = true,
-                    _ => {}
-                }
-            }
-            Ok(Event::End(e)) => {
-                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                if name == "data_block" {
-                    in_data_block = false;
-                }
-            }
-            Ok(Event::Eof) => break,
-            Err(e) => {
-                return Err(OpenSrsError::XmlDeserialize(format!(
-                    "XML parse error: {}",
-                    e
-                )));
-            }
-            _ => {}
-        }
-        buf.clear();
-    }
-
-    Ok(GetDomainsByExpireDateResponse {
-        is_success,
-        response_code,
-        response_text,
-        attributes: GetDomainsByExpireDateResponseAttrs {
-            page,
-            total,
-            remainder,
-            exp_domains,
-        },
-    })
+/// Deserialize an OpenSRS XML response into its command's response type
+pub fn deserialize_response<R: OpsResponse>(xml: &str) -> Result<R> {
+    R::parse(xml)
 }
 
 /// Calculate Content-Length (OpenSRS requires exact byte count)
@@ -215,6 +50,7 @@ pub fn calculate_content_length(xml: &str) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::opensrs::types::{GetDomainsByExpireDateAttrs, GetDomainsByExpireDateRequest};
 
     #[test]
     fn test_serialize_basic_request() {